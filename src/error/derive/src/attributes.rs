@@ -0,0 +1,115 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use proc_macro2::{Ident, Span};
+use syn::{Attribute, Error, LitInt, LitStr, Path, Result};
+
+pub(super) struct EnumAttributes {
+	pub(super) krate: Path,
+	pub(super) severity: Ident,
+}
+
+impl EnumAttributes {
+	/// Parses the `#[kind(..)]` attributes of the enum. Unknown keys are rejected.
+	pub(super) fn parse(attrs: &[Attribute]) -> Result<Self> {
+		let mut krate = None;
+		let mut severity = None;
+		for attr in attrs.iter().filter(|attr| attr.path().is_ident("kind")) {
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("crate") {
+					let path: LitStr = meta.value()?.parse()?;
+					krate = Some(path.parse()?);
+					Ok(())
+				} else if meta.path.is_ident("severity") {
+					severity = Some(parse_severity(&meta.value()?.parse()?)?);
+					Ok(())
+				} else {
+					Err(meta.error("expected `crate` or `severity`"))
+				}
+			})?;
+		}
+		Ok(Self {
+			krate: krate.unwrap_or_else(|| syn::parse_quote!(::astral_error)),
+			severity: severity.unwrap_or_else(|| Ident::new("Recoverable", Span::call_site())),
+		})
+	}
+}
+
+pub(super) struct VariantAttributes {
+	pub(super) display: Option<LitStr>,
+	pub(super) code: Option<LitInt>,
+	pub(super) severity: Option<Ident>,
+}
+
+impl VariantAttributes {
+	/// Parses the `#[kind(..)]` attributes of a variant.
+	///
+	/// The `display` string is checked when the generated `Display` implementation is compiled, so
+	/// it may only reference fields of the variant:
+	///
+	/// ```compile_fail,E0425
+	/// use astral_error::ErrorKind;
+	///
+	/// #[derive(Debug, ErrorKind)]
+	/// enum AssetKind {
+	///     #[kind(display = "{file} was not found")]
+	///     NotFound { name: String },
+	/// }
+	/// ```
+	pub(super) fn parse(attrs: &[Attribute]) -> Result<Self> {
+		let mut display = None;
+		let mut code = None;
+		let mut severity = None;
+		for attr in attrs.iter().filter(|attr| attr.path().is_ident("kind")) {
+			attr.parse_nested_meta(|meta| {
+				if meta.path.is_ident("display") {
+					display = Some(meta.value()?.parse()?);
+					Ok(())
+				} else if meta.path.is_ident("code") {
+					let value: LitInt = meta.value()?.parse()?;
+					let _: u32 = value.base10_parse()?;
+					code = Some(value);
+					Ok(())
+				} else if meta.path.is_ident("severity") {
+					severity = Some(parse_severity(&meta.value()?.parse()?)?);
+					Ok(())
+				} else {
+					Err(meta.error("expected `display`, `code` or `severity`"))
+				}
+			})?;
+		}
+		Ok(Self {
+			display,
+			code,
+			severity,
+		})
+	}
+}
+
+/// Parses the value of a `severity` key into the variant of `Severity`.
+fn parse_severity(value: &LitStr) -> Result<Ident> {
+	let variant = match value.value().as_str() {
+		"recoverable" => "Recoverable",
+		"retryable" => "Retryable",
+		"fatal" => "Fatal",
+		_ => {
+			return Err(Error::new(
+				value.span(),
+				"expected `\"recoverable\"`, `\"retryable\"` or `\"fatal\"`",
+			));
+		}
+	};
+	Ok(Ident::new(variant, value.span()))
+}
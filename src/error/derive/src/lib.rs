@@ -0,0 +1,249 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+//! Derive macros for [`astral-error`].
+//!
+//! This crate is re-exported by `astral-error`, if its `derive` feature is enabled. See the
+//! documentation of the `ErrorKind` trait there for details.
+//!
+//! [`astral-error`]: https://docs.rs/astral-error
+
+#![warn(
+	future_incompatible,
+	nonstandard_style,
+	rust_2018_compatibility,
+	rust_2018_idioms,
+	unused,
+	macro_use_extern_crate,
+	missing_copy_implementations,
+	missing_debug_implementations,
+	missing_docs,
+	single_use_lifetimes,
+	trivial_casts,
+	trivial_numeric_casts,
+	unreachable_pub,
+	unused_import_braces,
+	unused_lifetimes,
+	unused_qualifications,
+	unused_results,
+	variant_size_differences,
+	clippy::pedantic
+)]
+
+mod attributes;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, LitStr, Result};
+
+use self::attributes::{EnumAttributes, VariantAttributes};
+
+/// Derives `Display`, `ErrorKind` and `Classify` for an enum.
+///
+/// See the documentation of the `ErrorKind` trait in `astral-error` for the supported attributes.
+#[proc_macro_derive(ErrorKind, attributes(kind))]
+pub fn derive_error_kind(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+	let input = parse_macro_input!(input as DeriveInput);
+	expand(&input)
+		.unwrap_or_else(Error::into_compile_error)
+		.into()
+}
+
+/// Expands the derive for `input`, which has to be an enum.
+///
+/// The formatter in the generated `Display` implementation does not clash with field names:
+///
+/// ```
+/// use astral_error::ErrorKind;
+///
+/// #[derive(Debug, ErrorKind)]
+/// enum FormatKind {
+///     #[kind(display = "invalid format {fmt}")]
+///     Invalid { fmt: String },
+/// }
+///
+/// let kind = FormatKind::Invalid { fmt: "png".to_string() };
+/// assert_eq!(kind.to_string(), "invalid format png");
+/// ```
+fn expand(input: &DeriveInput) -> Result<TokenStream> {
+	let Data::Enum(data) = &input.data else {
+		return Err(Error::new_spanned(
+			input,
+			"`ErrorKind` can only be derived for enums",
+		));
+	};
+	let enum_attributes = EnumAttributes::parse(&input.attrs)?;
+	let krate = &enum_attributes.krate;
+
+	let mut display_arms = Vec::new();
+	let mut code_arms = Vec::new();
+	let mut severity_arms = Vec::new();
+	for variant in &data.variants {
+		let attributes = VariantAttributes::parse(&variant.attrs)?;
+		let ident = &variant.ident;
+
+		let pattern = match &variant.fields {
+			Fields::Named(fields) => {
+				let names = fields
+					.named
+					.iter()
+					.map(|field| field.ident.clone())
+					.collect::<Vec<_>>();
+				quote!(Self::#ident { #(#names),* })
+			}
+			Fields::Unnamed(fields) => {
+				let names = (0..fields.unnamed.len()).map(|index| format_ident!("_{}", index));
+				quote!(Self::#ident(#(#names),*))
+			}
+			Fields::Unit => quote!(Self::#ident),
+		};
+		let wildcard = match &variant.fields {
+			Fields::Named(_) => quote!(Self::#ident { .. }),
+			Fields::Unnamed(_) => quote!(Self::#ident(..)),
+			Fields::Unit => quote!(Self::#ident),
+		};
+
+		let display = match &attributes.display {
+			Some(display) => positional_to_named(display),
+			None => LitStr::new(&ident.to_string(), ident.span()),
+		};
+		display_arms.push(quote!(#pattern => ::core::write!(__formatter, #display)));
+
+		let code = attributes.code.as_ref().map_or_else(
+			|| quote!(::core::option::Option::None),
+			|code| quote!(::core::option::Option::Some(#code)),
+		);
+		code_arms.push(quote!(#wildcard => #code));
+
+		let severity = attributes
+			.severity
+			.as_ref()
+			.unwrap_or(&enum_attributes.severity);
+		severity_arms.push(quote!(#wildcard => #krate::Severity::#severity));
+	}
+
+	let name = &input.ident;
+	let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+	let no_variants = data.variants.is_empty();
+	let display_body = if no_variants {
+		quote!(match *self {})
+	} else {
+		quote!(match self { #(#display_arms,)* })
+	};
+	let code_body = if no_variants {
+		quote!(match *self {})
+	} else {
+		quote!(match self { #(#code_arms,)* })
+	};
+	let severity_body = if no_variants {
+		quote!(match *self {})
+	} else {
+		quote!(match self { #(#severity_arms,)* })
+	};
+
+	Ok(quote! {
+		impl #impl_generics ::core::fmt::Display for #name #ty_generics #where_clause {
+			#[allow(unused_variables)]
+			fn fmt(&self, __formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+				#display_body
+			}
+		}
+
+		impl #impl_generics #krate::ErrorKind for #name #ty_generics #where_clause {
+			fn code(&self) -> ::core::option::Option<u32> {
+				#code_body
+			}
+		}
+
+		impl #impl_generics #krate::Classify for #name #ty_generics #where_clause {
+			fn severity(&self) -> #krate::Severity {
+				#severity_body
+			}
+		}
+	})
+}
+
+/// Replaces positional arguments like `{0}` by the names of the bindings of tuple fields (`{_0}`).
+fn positional_to_named(display: &LitStr) -> LitStr {
+	let value = display.value();
+	let mut result = String::with_capacity(value.len());
+	let mut chars = value.chars().peekable();
+	while let Some(c) = chars.next() {
+		result.push(c);
+		if c == '{' {
+			match chars.peek() {
+				Some('{') => result.push(chars.next().unwrap_or('{')),
+				Some(next) if next.is_ascii_digit() => result.push('_'),
+				_ => {}
+			}
+		}
+	}
+	LitStr::new(&result, display.span())
+}
+
+#[cfg(test)]
+mod test {
+	use syn::parse_quote;
+
+	use super::*;
+
+	fn error(input: &DeriveInput) -> String {
+		match expand(input) {
+			Ok(_) => panic!("expected an error"),
+			Err(error) => error.to_string(),
+		}
+	}
+
+	#[test]
+	fn test_not_an_enum() {
+		let input = parse_quote! {
+			struct AssetKind;
+		};
+		assert_eq!(error(&input), "`ErrorKind` can only be derived for enums");
+	}
+
+	#[test]
+	fn test_unknown_key() {
+		let input = parse_quote! {
+			#[kind(retry = 3)]
+			enum AssetKind {
+				NotFound,
+			}
+		};
+		assert_eq!(error(&input), "expected `crate` or `severity`");
+
+		let input = parse_quote! {
+			enum AssetKind {
+				#[kind(retry = 3)]
+				NotFound,
+			}
+		};
+		assert_eq!(error(&input), "expected `display`, `code` or `severity`");
+	}
+
+	#[test]
+	fn test_invalid_severity() {
+		let input = parse_quote! {
+			enum AssetKind {
+				#[kind(severity = "critical")]
+				NotFound,
+			}
+		};
+		assert_eq!(
+			error(&input),
+			"expected `\"recoverable\"`, `\"retryable\"` or `\"fatal\"`"
+		);
+	}
+}
@@ -0,0 +1,77 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use alloc::{borrow::Cow, boxed::Box};
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A key-value pair attached to an [`Error`] by [`Error::with`].
+///
+/// [`Error`]: struct.Error.html
+/// [`Error::with`]: struct.Error.html#method.with
+pub struct Attachment {
+	key: Cow<'static, str>,
+	value: Box<dyn Display + Send + Sync>,
+}
+
+impl Attachment {
+	pub(super) fn new(
+		key: impl Into<Cow<'static, str>>,
+		value: Box<dyn Display + Send + Sync>,
+	) -> Self {
+		Self {
+			key: key.into(),
+			value,
+		}
+	}
+
+	/// Returns the key of the attachment.
+	#[must_use]
+	pub fn key(&self) -> &str {
+		&self.key
+	}
+
+	/// Returns the key, if it was passed to [`Error::with`] and is not owned, e.g. because it was
+	/// received in an `ErrorReport`.
+	///
+	/// [`Error::with`]: struct.Error.html#method.with
+	#[cfg_attr(not(feature = "std"), allow(dead_code))]
+	pub(super) fn static_key(&self) -> Option<&'static str> {
+		match self.key {
+			Cow::Borrowed(key) => Some(key),
+			Cow::Owned(_) => None,
+		}
+	}
+
+	/// Returns the value of the attachment.
+	#[must_use]
+	pub fn value(&self) -> &(dyn Display + Send + Sync) {
+		self.value.as_ref()
+	}
+}
+
+impl Debug for Attachment {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.debug_tuple("Attachment")
+			.field(&self.key)
+			.field(&format_args!("{}", self.value))
+			.finish()
+	}
+}
+
+impl Display for Attachment {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		write!(fmt, "{}: {}", self.key, self.value)
+	}
+}
@@ -0,0 +1,66 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+#[cfg(feature = "std")]
+pub use std::backtrace::Backtrace;
+
+#[cfg(not(feature = "std"))]
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// Placeholder for `std::backtrace::Backtrace` without the `std` feature.
+///
+/// Backtraces cannot be captured without the standard library, so this type has no values and
+/// [`Error::backtrace`] always returns `None`.
+///
+/// [`Error::backtrace`]: struct.Error.html#method.backtrace
+#[cfg(not(feature = "std"))]
+#[allow(missing_copy_implementations)]
+pub enum Backtrace {}
+
+#[cfg(not(feature = "std"))]
+impl Debug for Backtrace {
+	fn fmt(&self, _: &mut Formatter<'_>) -> fmt::Result {
+		match *self {}
+	}
+}
+
+#[cfg(not(feature = "std"))]
+impl Display for Backtrace {
+	fn fmt(&self, _: &mut Formatter<'_>) -> fmt::Result {
+		match *self {}
+	}
+}
+
+/// Captures a backtrace, if the `backtrace` feature is enabled and backtraces are enabled by the
+/// environment.
+///
+/// Like [`Backtrace::capture`], this respects the `RUST_LIB_BACKTRACE` and `RUST_BACKTRACE`
+/// environment variables, so capturing is cheap unless one of them is set.
+#[cfg(feature = "backtrace")]
+pub(super) fn capture() -> Option<Backtrace> {
+	use std::backtrace::BacktraceStatus;
+
+	let backtrace = Backtrace::capture();
+	if backtrace.status() == BacktraceStatus::Captured {
+		Some(backtrace)
+	} else {
+		None
+	}
+}
+
+#[cfg(not(feature = "backtrace"))]
+pub(super) fn capture() -> Option<Backtrace> {
+	None
+}
@@ -0,0 +1,56 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use core::{error, iter::FusedIterator};
+
+/// An iterator over an error and its sources.
+///
+/// This struct is created by [`Error::chain`]. See its documentation for more.
+///
+/// [`Error::chain`]: struct.Error.html#method.chain
+#[derive(Debug, Clone)]
+pub struct Chain<'a> {
+	next: Option<&'a (dyn error::Error + 'static)>,
+}
+
+impl<'a> Chain<'a> {
+	/// Creates an iterator over `error` and its sources.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use std::io;
+	///
+	/// use astral::error::Chain;
+	///
+	/// let error = io::Error::new(io::ErrorKind::Other, "oh no!");
+	/// assert_eq!(Chain::new(&error).count(), 1);
+	/// ```
+	pub fn new(error: &'a (dyn error::Error + 'static)) -> Self {
+		Self { next: Some(error) }
+	}
+}
+
+impl<'a> Iterator for Chain<'a> {
+	type Item = &'a (dyn error::Error + 'static);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let next = self.next?;
+		self.next = next.source();
+		Some(next)
+	}
+}
+
+impl FusedIterator for Chain<'_> {}
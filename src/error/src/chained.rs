@@ -1,23 +0,0 @@
-// Copyright (c) Astral Developers
-//
-// Licensed under the Apache License, Version 2.0 (the "License");
-// you may not use this file except in compliance with the License.
-// You may obtain a copy of the License at
-//
-//     http://www.apache.org/licenses/LICENSE-2.0
-//
-// Unless required by applicable law or agreed to in writing, software
-// distributed under the License is distributed on an "AS IS" BASIS,
-// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
-// See the License for the specific language governing permissions and
-// limitations under the License.
-// Written by Tim Diekmann <tim.diekmann@3dvision.de>, November 2018
-
-use std::error;
-
-#[derive(Debug)]
-pub(super) struct Chained<Kind> {
-	pub(super) kind: Kind,
-	pub(super) error: Box<dyn error::Error + Send + Sync>,
-	pub(super) source: Box<dyn error::Error + Send + Sync>,
-}
@@ -0,0 +1,104 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use alloc::boxed::Box;
+use core::{
+	any::Any,
+	fmt::{self, Debug, Display, Formatter},
+	panic::Location,
+};
+
+/// A context layer of an [`Error`].
+///
+/// Frames are pushed by [`Error::context`] and [`Error::context_with_data`] and consist of a
+/// message, optional structured data and the location where they were added.
+///
+/// [`Error`]: struct.Error.html
+/// [`Error::context`]: struct.Error.html#method.context
+/// [`Error::context_with_data`]: struct.Error.html#method.context_with_data
+pub struct Frame {
+	message: Box<dyn Display + Send + Sync>,
+	data: Option<Box<dyn Any + Send + Sync>>,
+	location: &'static Location<'static>,
+}
+
+impl Frame {
+	pub(super) fn new(
+		message: Box<dyn Display + Send + Sync>,
+		data: Option<Box<dyn Any + Send + Sync>>,
+		location: &'static Location<'static>,
+	) -> Self {
+		Self {
+			message,
+			data,
+			location,
+		}
+	}
+
+	/// Returns the message of this frame.
+	#[must_use]
+	pub fn message(&self) -> &(dyn Display + Send + Sync) {
+		self.message.as_ref()
+	}
+
+	/// Returns the data attached to this frame, if it is of type `T`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::Error;
+	///
+	/// #[derive(Debug)]
+	/// enum MyErrorKind {
+	///     Variant,
+	/// }
+	///
+	/// let error = Error::new(MyErrorKind::Variant, "oh no!")
+	///     .context_with_data("loading asset", String::from("models/cube.obj"));
+	///
+	/// let frame = &error.frames()[0];
+	/// assert_eq!(frame.data::<String>().unwrap(), "models/cube.obj");
+	/// assert!(frame.data::<u32>().is_none());
+	/// ```
+	#[must_use]
+	pub fn data<T>(&self) -> Option<&T>
+	where
+		T: Any,
+	{
+		self.data.as_ref()?.downcast_ref()
+	}
+
+	/// Returns the location in the source code, where this frame was added.
+	#[must_use]
+	pub fn location(&self) -> &'static Location<'static> {
+		self.location
+	}
+}
+
+impl Debug for Frame {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.debug_struct("Frame")
+			.field("message", &format_args!("{}", self.message))
+			.field("data", &self.data.is_some())
+			.field("location", &format_args!("{}", self.location))
+			.finish()
+	}
+}
+
+impl Display for Frame {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.message, fmt)
+	}
+}
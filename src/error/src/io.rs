@@ -0,0 +1,225 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{
+	error,
+	fmt::{self, Debug, Display, Formatter},
+	io,
+};
+
+use super::{Classify, Error, ErrorKind, Severity};
+
+/// An error kind for I/O operations, which mirrors [`io::ErrorKind`].
+///
+/// Kinds, which are not mapped to a distinct variant, are mapped to [`Other`].
+///
+/// [`io::ErrorKind`]: std::io::ErrorKind
+/// [`Other`]: #variant.Other
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+///
+/// use astral::error::{Classify, IoKind, Severity};
+///
+/// let kind = IoKind::from(io::ErrorKind::NotFound);
+/// assert_eq!(kind, IoKind::NotFound);
+/// assert_eq!(io::ErrorKind::from(kind), io::ErrorKind::NotFound);
+///
+/// assert_eq!(IoKind::from(io::ErrorKind::Interrupted).severity(), Severity::Retryable);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum IoKind {
+	NotFound,
+	PermissionDenied,
+	AlreadyExists,
+	InvalidInput,
+	InvalidData,
+	UnexpectedEof,
+	WriteZero,
+	WouldBlock,
+	TimedOut,
+	Interrupted,
+	Unsupported,
+	OutOfMemory,
+	Other,
+}
+
+impl From<io::ErrorKind> for IoKind {
+	fn from(kind: io::ErrorKind) -> Self {
+		match kind {
+			io::ErrorKind::NotFound => IoKind::NotFound,
+			io::ErrorKind::PermissionDenied => IoKind::PermissionDenied,
+			io::ErrorKind::AlreadyExists => IoKind::AlreadyExists,
+			io::ErrorKind::InvalidInput => IoKind::InvalidInput,
+			io::ErrorKind::InvalidData => IoKind::InvalidData,
+			io::ErrorKind::UnexpectedEof => IoKind::UnexpectedEof,
+			io::ErrorKind::WriteZero => IoKind::WriteZero,
+			io::ErrorKind::WouldBlock => IoKind::WouldBlock,
+			io::ErrorKind::TimedOut => IoKind::TimedOut,
+			io::ErrorKind::Interrupted => IoKind::Interrupted,
+			io::ErrorKind::Unsupported => IoKind::Unsupported,
+			io::ErrorKind::OutOfMemory => IoKind::OutOfMemory,
+			_ => IoKind::Other,
+		}
+	}
+}
+
+impl From<IoKind> for io::ErrorKind {
+	fn from(kind: IoKind) -> Self {
+		match kind {
+			IoKind::NotFound => io::ErrorKind::NotFound,
+			IoKind::PermissionDenied => io::ErrorKind::PermissionDenied,
+			IoKind::AlreadyExists => io::ErrorKind::AlreadyExists,
+			IoKind::InvalidInput => io::ErrorKind::InvalidInput,
+			IoKind::InvalidData => io::ErrorKind::InvalidData,
+			IoKind::UnexpectedEof => io::ErrorKind::UnexpectedEof,
+			IoKind::WriteZero => io::ErrorKind::WriteZero,
+			IoKind::WouldBlock => io::ErrorKind::WouldBlock,
+			IoKind::TimedOut => io::ErrorKind::TimedOut,
+			IoKind::Interrupted => io::ErrorKind::Interrupted,
+			IoKind::Unsupported => io::ErrorKind::Unsupported,
+			IoKind::OutOfMemory => io::ErrorKind::OutOfMemory,
+			IoKind::Other => io::ErrorKind::Other,
+		}
+	}
+}
+
+impl Display for IoKind {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&io::Error::from(io::ErrorKind::from(*self)), fmt)
+	}
+}
+
+impl ErrorKind for IoKind {}
+
+impl Classify for IoKind {
+	fn severity(&self) -> Severity {
+		io::ErrorKind::from(*self).severity()
+	}
+}
+
+/// Embeds the whole error as inner error of an [`io::Error`].
+///
+/// The kind of the `io::Error` is taken from the first [`IoKind`] in the chain of the error. If
+/// there is none, the kind of the first `io::Error` in the chain is used, or
+/// [`io::ErrorKind::Other`] otherwise. The original error can be recovered by [`Error::from_io`].
+///
+/// [`io::Error`]: std::io::Error
+/// [`IoKind`]: enum.IoKind.html
+/// [`io::ErrorKind::Other`]: std::io::ErrorKind::Other
+/// [`Error::from_io`]: struct.Error.html#method.from_io
+///
+/// # Examples
+///
+/// ```
+/// use std::io::{self, Read};
+///
+/// use astral::error::{Error, IoKind, ResultExt};
+///
+/// struct Archive;
+///
+/// impl Read for Archive {
+///     fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+///         let error = io::Error::new(io::ErrorKind::InvalidData, "corrupt entry");
+///         Err(error).io_context("assets.pak")?
+///     }
+/// }
+///
+/// let error = Archive.read(&mut [0; 16]).unwrap_err();
+/// assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+///
+/// let error = Error::<IoKind>::from_io(error).unwrap();
+/// assert_eq!(error.kind(), &IoKind::InvalidData);
+/// assert_eq!(error.attachment("path").unwrap().to_string(), "assets.pak");
+/// ```
+///
+/// An [`IoKind`] is used, even if the chain contains no `io::Error`:
+///
+/// ```
+/// use std::io;
+///
+/// use astral::error::{err, Error, IoKind};
+///
+/// let error: io::Error = err!(IoKind::NotFound, "assets.pak").into();
+/// assert_eq!(error.kind(), io::ErrorKind::NotFound);
+///
+/// # #[derive(Debug)]
+/// # struct LoadKind;
+/// # impl std::fmt::Display for LoadKind {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         std::fmt::Debug::fmt(self, f)
+/// #     }
+/// # }
+/// let error = Error::new(IoKind::PermissionDenied, "assets.pak");
+/// let error: io::Error = Error::chained(LoadKind, "could not load assets", error).into();
+/// assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+/// ```
+impl<Kind> From<Error<Kind>> for io::Error
+where
+	Kind: Debug + Display + Send + Sync + 'static,
+{
+	fn from(error: Error<Kind>) -> Self {
+		let kind = error
+			.find_kind::<IoKind>()
+			.map(|&kind| io::ErrorKind::from(kind))
+			.or_else(|| error.find_cause::<io::Error>().map(io::Error::kind))
+			.unwrap_or(io::ErrorKind::Other);
+		io::Error::new(kind, error)
+	}
+}
+
+impl<Kind> Error<Kind>
+where
+	Kind: Debug + Display + Send + Sync + 'static,
+{
+	/// Recovers an error, which was converted into an [`io::Error`].
+	///
+	/// # Errors
+	///
+	/// Returns the `io::Error` unchanged, if its inner error is not an `Error<Kind>`.
+	///
+	/// [`io::Error`]: std::io::Error
+	///
+	/// # Example
+	///
+	/// ```
+	/// use std::io;
+	///
+	/// use astral::error::{Error, IoKind};
+	///
+	/// let error = io::Error::from(Error::new(IoKind::NotFound, "file not found"));
+	/// let error = Error::<IoKind>::from_io(error).unwrap();
+	/// assert_eq!(error.kind(), &IoKind::NotFound);
+	///
+	/// let error = io::Error::from(io::ErrorKind::NotFound);
+	/// assert!(Error::<IoKind>::from_io(error).is_err());
+	/// ```
+	pub fn from_io(error: io::Error) -> Result<Self, io::Error> {
+		let inner = error.get_ref();
+		if !inner.is_some_and(<dyn error::Error + Send + Sync>::is::<Self>) {
+			return Err(error);
+		}
+		let kind = error.kind();
+		let inner = error.into_inner();
+		match inner.map(<dyn error::Error + Send + Sync>::downcast::<Self>) {
+			Some(Ok(error)) => Ok(*error),
+			Some(Err(inner)) => Err(io::Error::new(kind, inner)),
+			None => Err(io::Error::from(kind)),
+		}
+	}
+}
@@ -0,0 +1,179 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use core::fmt::{Debug, Display};
+
+use super::Error;
+
+/// Common functionality of error kinds.
+///
+/// An error kind may provide a stable numeric code, which identifies it e.g. in logs or across
+/// processes.
+///
+/// With the `derive` feature, `ErrorKind` can be derived for enums together with `Display` and
+/// [`Classify`]. The variants are configured by the `#[kind(...)]` attribute:
+///
+/// - `display = "..."`: the message of the variant. Fields of struct variants can be used by
+///   name, fields of tuple variants by their index. Defaults to the name of the variant.
+/// - `code = 42`: the code of the variant.
+/// - `severity = "recoverable" | "retryable" | "fatal"`: the severity of the variant. It may
+///   also be set on the enum as default for all variants.
+///
+/// If the `astral-error` crate is not available as `astral_error`, its path can be specified by
+/// `#[kind(crate = "astral::error")]` on the enum.
+///
+/// [`Classify`]: trait.Classify.html
+///
+/// # Example
+///
+#[cfg_attr(feature = "derive", doc = "```")]
+#[cfg_attr(not(feature = "derive"), doc = "```ignore")]
+/// use astral::error::{Classify, Error, ErrorKind, Severity};
+///
+/// #[derive(Debug, ErrorKind)]
+/// #[kind(crate = "astral::error")]
+/// enum AssetKind {
+///     #[kind(display = "asset not found", code = 1)]
+///     NotFound,
+///     #[kind(display = "invalid header in {path}", code = 2, severity = "fatal")]
+///     InvalidHeader { path: String },
+///     #[kind(display = "{0} is locked", severity = "retryable")]
+///     Locked(String),
+/// }
+///
+/// let kind = AssetKind::InvalidHeader { path: "models/cube.obj".to_string() };
+/// assert_eq!(kind.to_string(), "invalid header in models/cube.obj");
+/// assert_eq!(kind.code(), Some(2));
+/// assert_eq!(kind.severity(), Severity::Fatal);
+///
+/// let kind = AssetKind::Locked("textures/wood.png".to_string());
+/// assert_eq!(kind.to_string(), "textures/wood.png is locked");
+/// assert_eq!(kind.code(), None);
+/// assert_eq!(kind.severity(), Severity::Retryable);
+///
+/// let error = Error::from(AssetKind::NotFound);
+/// assert_eq!(error.to_string(), "asset not found");
+/// assert_eq!(error.severity(), Severity::Recoverable);
+/// ```
+pub trait ErrorKind: Debug + Display {
+	/// Returns the stable numeric code of this kind, if any.
+	fn code(&self) -> Option<u32> {
+		None
+	}
+}
+
+/// Conversion from the error kind of another subsystem.
+///
+/// Implementing `KindFrom<A>` for `B` allows to convert an `Error<A>` into an `Error<B>` with
+/// [`Error::kind_into`], which keeps the original error as source.
+///
+/// A blanket `From<Error<A>> for Error<B>` implementation would overlap with the reflexive
+/// `From<T> for T` implementation, so the conversion cannot be done implicitly by the `?`
+/// operator. Use [`KindResultExt::kind_into`] instead.
+///
+/// [`Error::kind_into`]: struct.Error.html#method.kind_into
+/// [`KindResultExt::kind_into`]: trait.KindResultExt.html#tymethod.kind_into
+///
+/// # Example
+///
+/// ```
+/// # use std::fmt::{self, Debug, Display, Formatter};
+/// # #[derive(Debug)] enum StringKind { InvalidUtf8 }
+/// # impl Display for StringKind {
+/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// #         Debug::fmt(self, f)
+/// #     }
+/// # }
+/// # #[derive(Debug, PartialEq)] enum AssetKind { Invalid }
+/// # impl Display for AssetKind {
+/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// #         Debug::fmt(self, f)
+/// #     }
+/// # }
+/// use astral::error::{Error, KindFrom, KindResultExt};
+///
+/// impl KindFrom<StringKind> for AssetKind {
+///     fn kind_from(_: &StringKind) -> Self {
+///         AssetKind::Invalid
+///     }
+/// }
+///
+/// fn parse() -> Result<(), Error<StringKind>> {
+///     Err(Error::new(StringKind::InvalidUtf8, "invalid byte sequence"))
+/// }
+///
+/// fn load() -> Result<(), Error<AssetKind>> {
+///     parse().kind_into::<AssetKind>()?;
+///     Ok(())
+/// }
+///
+/// let error = load().unwrap_err();
+/// assert_eq!(error.kind(), &AssetKind::Invalid);
+/// assert!(error.find_kind::<StringKind>().is_some());
+/// ```
+pub trait KindFrom<Kind> {
+	/// Returns the kind corresponding to `kind`.
+	fn kind_from(kind: &Kind) -> Self;
+}
+
+/// Extension methods for [`Result`]s with an [`Error`] to convert the error kind.
+///
+/// [`Error`]: struct.Error.html
+pub trait KindResultExt<T, Kind> {
+	/// Converts the error into an `Error<K>` by [`Error::kind_into`].
+	///
+	/// # Errors
+	///
+	/// Returns the converted error, if `self` is an `Err`.
+	///
+	/// [`Error::kind_into`]: struct.Error.html#method.kind_into
+	fn kind_into<K>(self) -> Result<T, Error<K>>
+	where
+		K: KindFrom<Kind>;
+
+	/// Remaps the kind of the error by [`Error::map_kind`].
+	///
+	/// # Errors
+	///
+	/// Returns the remapped error, if `self` is an `Err`.
+	///
+	/// [`Error::map_kind`]: struct.Error.html#method.map_kind
+	fn map_kind<K, F>(self, op: F) -> Result<T, Error<K>>
+	where
+		F: FnOnce(Kind) -> K;
+}
+
+impl<T, Kind> KindResultExt<T, Kind> for Result<T, Error<Kind>>
+where
+	Kind: Debug + Display + Send + Sync + 'static,
+{
+	#[track_caller]
+	fn kind_into<K>(self) -> Result<T, Error<K>>
+	where
+		K: KindFrom<Kind>,
+	{
+		match self {
+			Ok(value) => Ok(value),
+			Err(error) => Err(error.kind_into()),
+		}
+	}
+
+	fn map_kind<K, F>(self, op: F) -> Result<T, Error<K>>
+	where
+		F: FnOnce(Kind) -> K,
+	{
+		self.map_err(|error| error.map_kind(op))
+	}
+}
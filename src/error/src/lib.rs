@@ -14,7 +14,34 @@
 // Written by Tim Diekmann <tim.diekmann@3dvision.de>, November 2018
 
 //! Traits and structures for working with Errors in the Astral Engine.
+//!
+//! # Features
+//!
+//! - `std` (default): Enables the integrations with the standard library: [`std::io::Error`]
+//!   interop, [`ErrorReport`], [`retry`], the [`panic`] hook, [`telemetry`], slog integration and
+//!   backtraces. Without it, the crate is `no_std` and only requires `alloc`. [`Error`],
+//!   [`ResultExt`], [`OptionExt`], [`Report`] and [`MultiError`] are available in both modes.
+//! - `backtrace`: Captures a [`Backtrace`] when an error is created. Implies `std`.
+//! - `derive`: Enables `#[derive(ErrorKind)]`.
+//! - `testing`: Enables the [`testing`] module with assertion macros for errors.
+//!
+//! The minimum supported Rust version is 1.81, which stabilized `core::error::Error` and
+//! `PanicHookInfo`.
+//!
+//! [`std::io::Error`]: https://doc.rust-lang.org/std/io/struct.Error.html
+//! [`ErrorReport`]: struct.ErrorReport.html
+//! [`retry`]: fn.retry.html
+//! [`panic`]: panic/index.html
+//! [`telemetry`]: telemetry/index.html
+//! [`Error`]: struct.Error.html
+//! [`ResultExt`]: trait.ResultExt.html
+//! [`OptionExt`]: trait.OptionExt.html
+//! [`Report`]: struct.Report.html
+//! [`MultiError`]: struct.MultiError.html
+//! [`Backtrace`]: struct.Backtrace.html
+//! [`testing`]: testing/index.html
 
+#![cfg_attr(not(feature = "std"), no_std)]
 #![doc(
 	html_no_source,
 	html_logo_url = "https://astral-engine.github.io/docs/logo_astral.svg",
@@ -60,21 +87,73 @@
 	clippy::pedantic
 )]
 
-mod chained;
-mod custom;
+extern crate alloc;
+
+mod attachment;
+mod backtrace;
+mod chain;
+mod frame;
+#[cfg(feature = "std")]
+mod io;
+mod kind;
+#[cfg(feature = "std")]
+mod log;
+mod macros;
+mod multi;
 mod option_ext;
+#[cfg(feature = "std")]
+pub mod panic;
+#[cfg(feature = "std")]
+mod remote;
+mod report;
 mod repr;
 mod result;
 mod result_ext;
+#[cfg(feature = "std")]
+mod retry;
+mod severity;
+#[cfg(feature = "std")]
+pub mod telemetry;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use self::{
+	attachment::Attachment,
+	backtrace::Backtrace,
+	chain::Chain,
+	frame::Frame,
+	kind::{ErrorKind, KindFrom, KindResultExt},
+	multi::{CollectAll, MultiError},
+	option_ext::OptionExt,
+	report::Report,
+	result::Result,
+	result_ext::{FrameResultExt, ResultExt},
+	severity::{Classify, Severity},
+};
+
+#[cfg(feature = "std")]
+pub use self::{
+	io::IoKind,
+	remote::{Cause, ErrorReport, RemoteKind},
+	retry::{retry, RetryPolicy},
+};
+
+#[cfg(feature = "derive")]
+pub use astral_error_derive::ErrorKind;
 
-pub use self::{option_ext::OptionExt, result::Result, result_ext::ResultExt};
+#[doc(hidden)]
+pub use alloc::format as __format;
 
-use std::{
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+	any::Any,
 	error,
 	fmt::{self, Debug, Display, Formatter},
+	iter,
+	panic::Location,
 };
 
-use self::{chained::Chained, custom::Custom, repr::Repr};
+use self::repr::Repr;
 
 /// The generic error type for the Astral engine.
 ///
@@ -84,6 +163,10 @@ use self::{chained::Chained, custom::Custom, repr::Repr};
 /// It is useful but not necessary, that `Kind` implements [`Debug`] and
 /// [`Display`] so [`std::error::Error`] is implemented.
 ///
+/// The kind and all other data are stored in a single heap allocation, so
+/// `Error` is always one pointer wide, independent of `Kind`. This keeps
+/// `Result<T, Error<Kind>>` small on the success path.
+///
 /// [`Debug`]: std::fmt::Debug
 /// [`Display`]: std::fmt::Display
 /// [`Error`]: std::error::Error
@@ -115,7 +198,7 @@ use self::{chained::Chained, custom::Custom, repr::Repr};
 /// assert!(my_error2.source().is_none());
 /// ```
 pub struct Error<Kind> {
-	repr: Repr<Kind>,
+	repr: Box<Repr<Kind>>,
 }
 
 impl<Kind> Error<Kind> {
@@ -146,16 +229,12 @@ impl<Kind> Error<Kind> {
 	///
 	/// assert!(my_error2.source().is_none());
 	/// ```
+	#[track_caller]
 	pub fn new<E>(kind: Kind, error: E) -> Self
 	where
 		E: Into<Box<dyn error::Error + Send + Sync>>,
 	{
-		Self {
-			repr: Repr::Custom(Box::new(Custom {
-				kind,
-				error: error.into(),
-			})),
-		}
+		Self::from_parts(kind, Some(error.into()), None, Location::caller())
 	}
 
 	/// Creates a new error from a known kind of error as well as an arbitrary
@@ -191,20 +270,86 @@ impl<Kind> Error<Kind> {
 	/// # Some(())
 	/// # }
 	/// ```
+	#[track_caller]
 	pub fn chained<E, S>(kind: Kind, error: E, source: S) -> Self
 	where
 		E: Into<Box<dyn error::Error + Send + Sync>>,
 		S: Into<Box<dyn error::Error + Send + Sync>>,
 	{
+		Self::from_parts(
+			kind,
+			Some(error.into()),
+			Some(source.into()),
+			Location::caller(),
+		)
+	}
+
+	fn from_parts(
+		kind: Kind,
+		error: Option<Box<dyn error::Error + Send + Sync>>,
+		source: Option<Box<dyn error::Error + Send + Sync>>,
+		location: &'static Location<'static>,
+	) -> Self {
+		Self::from_parts_with_backtrace(kind, error, source, location, backtrace::capture())
+	}
+
+	fn from_parts_with_backtrace(
+		kind: Kind,
+		error: Option<Box<dyn error::Error + Send + Sync>>,
+		source: Option<Box<dyn error::Error + Send + Sync>>,
+		location: &'static Location<'static>,
+		backtrace: Option<Backtrace>,
+	) -> Self {
 		Self {
-			repr: Repr::Chained(Box::new(Chained {
+			repr: Box::new(Repr {
 				kind,
-				error: error.into(),
-				source: source.into(),
-			})),
+				error,
+				source,
+				frames: Vec::new(),
+				attachments: Vec::new(),
+				location,
+				backtrace,
+			}),
 		}
 	}
 
+	/// Creates a new error from a known kind of error, which wraps all given `errors` in a
+	/// [`MultiError`].
+	///
+	/// [`MultiError`]: struct.MultiError.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use std::fmt::{self, Debug, Display, Formatter};
+	/// # #[derive(Debug, PartialEq)] enum MyErrorKind { Asset, Directory }
+	/// # impl Display for MyErrorKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// use astral::error::{Error, MultiError};
+	///
+	/// let errors = vec![
+	///     Error::new(MyErrorKind::Asset, "missing texture"),
+	///     Error::new(MyErrorKind::Asset, "missing shader"),
+	/// ];
+	/// let error = Error::aggregate(MyErrorKind::Directory, errors);
+	///
+	/// assert_eq!(error.kind(), &MyErrorKind::Directory);
+	/// let errors = error.downcast_ref::<MultiError<MyErrorKind>>().unwrap();
+	/// assert_eq!(errors.len(), 2);
+	/// ```
+	#[track_caller]
+	pub fn aggregate<I>(kind: Kind, errors: I) -> Self
+	where
+		Kind: Debug + Display + Send + Sync + 'static,
+		I: IntoIterator<Item = Self>,
+	{
+		let errors = errors.into_iter().collect::<MultiError<Kind>>();
+		Self::new(kind, errors)
+	}
+
 	/// Returns a reference to the inner error wrapped by this error (if any).
 	///
 	/// If this `Error` was constructed via [`new`] or [`chained`] then this
@@ -236,6 +381,7 @@ impl<Kind> Error<Kind> {
 	///     print_error(&Error::new(MyErrorKind::Variant, "oh no!"));
 	/// }
 	/// ```
+	#[must_use]
 	pub fn get_ref(&self) -> Option<&(dyn error::Error + Send + Sync + 'static)> {
 		self.repr.get_ref()
 	}
@@ -342,10 +488,172 @@ impl<Kind> Error<Kind> {
 	/// # }
 	/// ```
 	#[inline]
+	#[must_use]
 	pub fn into_inner(self) -> Option<Box<dyn error::Error + Send + Sync>> {
 		self.repr.into_inner()
 	}
 
+	/// Returns a reference to the inner error, if it is of type `T`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use std::io;
+	///
+	/// use astral::error::Error;
+	///
+	/// #[derive(Debug)]
+	/// enum MyErrorKind {
+	///     Variant,
+	/// }
+	///
+	/// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+	/// let my_error = Error::new(MyErrorKind::Variant, io_error);
+	///
+	/// let io_error = my_error.downcast_ref::<io::Error>().unwrap();
+	/// assert_eq!(io_error.kind(), io::ErrorKind::NotFound);
+	/// assert!(my_error.downcast_ref::<std::fmt::Error>().is_none());
+	/// ```
+	#[must_use]
+	pub fn downcast_ref<T>(&self) -> Option<&T>
+	where
+		T: error::Error + 'static,
+	{
+		self.get_ref()?.downcast_ref()
+	}
+
+	/// Consumes the `Error`, returning its inner error, if it is of type `T`.
+	///
+	/// # Errors
+	///
+	/// If the inner error is not of type `T` or there is no inner error, the `Error` is returned
+	/// unchanged.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use std::{fmt, io};
+	///
+	/// use astral::error::Error;
+	///
+	/// #[derive(Debug)]
+	/// enum MyErrorKind {
+	///     Variant,
+	/// }
+	///
+	/// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+	/// let my_error = Error::new(MyErrorKind::Variant, io_error);
+	///
+	/// let my_error = my_error.downcast::<fmt::Error>().unwrap_err();
+	/// let io_error = my_error.downcast::<io::Error>().unwrap();
+	/// assert_eq!(io_error.kind(), io::ErrorKind::NotFound);
+	/// ```
+	pub fn downcast<T>(mut self) -> core::result::Result<T, Self>
+	where
+		T: error::Error + Send + Sync + 'static,
+	{
+		let inner = self.repr.error.take();
+		match inner.map(<dyn error::Error + Send + Sync>::downcast::<T>) {
+			Some(Ok(error)) => Ok(*error),
+			Some(Err(error)) => {
+				self.repr.error = Some(error);
+				Err(self)
+			}
+			None => Err(self),
+		}
+	}
+
+	/// Returns the first error of type `T` in the chain of this error.
+	///
+	/// Besides the errors returned by [`chain`], the inner errors of every `Error<Kind>` in the
+	/// chain are searched as well.
+	///
+	/// [`chain`]: #method.chain
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use std::fmt::{self, Debug, Display, Formatter};
+	/// # #[derive(Debug)] enum MyErrorKind { Variant }
+	/// # impl Display for MyErrorKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// use std::io;
+	///
+	/// use astral::error::Error;
+	///
+	/// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+	/// let error = Error::new(MyErrorKind::Variant, io_error);
+	/// let error = Error::chained(MyErrorKind::Variant, "could not load texture", error);
+	/// let error = Error::chained(MyErrorKind::Variant, "could not load model", error);
+	///
+	/// let io_error = error.find_cause::<io::Error>().unwrap();
+	/// assert_eq!(io_error.kind(), io::ErrorKind::NotFound);
+	/// ```
+	#[must_use]
+	pub fn find_cause<T>(&self) -> Option<&T>
+	where
+		Kind: Debug + Display + 'static,
+		T: error::Error + 'static,
+	{
+		self.causes().find_map(<dyn error::Error>::downcast_ref)
+	}
+
+	/// Returns the kind of the first `Error<K>` in the chain of this error.
+	///
+	/// This error itself is part of the chain, so if `K` is `Kind`, the kind of this error is
+	/// returned. Like [`find_cause`], the inner errors of every `Error<Kind>` are searched as well.
+	///
+	/// [`find_cause`]: #method.find_cause
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use std::fmt::{self, Debug, Display, Formatter};
+	/// # #[derive(Debug, PartialEq)] enum AssetKind { NotFound }
+	/// # impl Display for AssetKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// # #[derive(Debug)] enum LevelKind { Load }
+	/// # impl Display for LevelKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// use astral::error::Error;
+	///
+	/// let error = Error::new(AssetKind::NotFound, "models/cube.obj");
+	/// let error = Error::chained(LevelKind::Load, "could not load level", error);
+	///
+	/// assert_eq!(error.find_kind::<AssetKind>(), Some(&AssetKind::NotFound));
+	/// ```
+	#[must_use]
+	pub fn find_kind<K>(&self) -> Option<&K>
+	where
+		Kind: Debug + Display + 'static,
+		K: Debug + Display + 'static,
+	{
+		self.causes()
+			.find_map(<dyn error::Error>::downcast_ref::<Error<K>>)
+			.map(Error::kind)
+	}
+
+	/// Returns an iterator over the chain of this error including the inner errors of every
+	/// `Error<Kind>`.
+	fn causes(&self) -> impl Iterator<Item = &(dyn error::Error + 'static)>
+	where
+		Kind: Debug + Display + 'static,
+	{
+		self.chain().flat_map(|error| {
+			let inner = error.downcast_ref::<Self>().and_then(Self::get_ref);
+			iter::once(error).chain(inner.map(|inner| -> &(dyn error::Error + 'static) { inner }))
+		})
+	}
+
 	/// Returns the corresponding `Kind` for this error.
 	///
 	/// # Example
@@ -362,8 +670,402 @@ impl<Kind> Error<Kind> {
 	/// assert_eq!(*my_error.kind(), MyErrorKind::Variant);
 	/// ```
 	#[inline]
+	#[must_use]
 	pub fn kind(&self) -> &Kind {
-		self.repr.kind()
+		&self.repr.kind
+	}
+
+	/// Converts this error into an error of kind `K`, keeping this error as source.
+	///
+	/// The new kind is determined by [`KindFrom`]. The new error has no inner error, so it displays
+	/// its kind.
+	///
+	/// [`KindFrom`]: trait.KindFrom.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use std::fmt::{self, Debug, Display, Formatter};
+	/// # #[derive(Debug)] enum StringKind { InvalidUtf8 }
+	/// # impl Display for StringKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// # #[derive(Debug, PartialEq)] enum AssetKind { Invalid }
+	/// # impl Display for AssetKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// use std::error::Error as _;
+	///
+	/// use astral::error::{Error, KindFrom};
+	///
+	/// impl KindFrom<StringKind> for AssetKind {
+	///     fn kind_from(_: &StringKind) -> Self {
+	///         AssetKind::Invalid
+	///     }
+	/// }
+	///
+	/// let error = Error::new(StringKind::InvalidUtf8, "invalid byte sequence");
+	/// let error: Error<AssetKind> = error.kind_into();
+	///
+	/// assert_eq!(error.kind(), &AssetKind::Invalid);
+	/// assert_eq!(error.source().unwrap().to_string(), "invalid byte sequence");
+	/// ```
+	#[must_use]
+	#[track_caller]
+	pub fn kind_into<K>(self) -> Error<K>
+	where
+		Kind: Debug + Display + Send + Sync + 'static,
+		K: KindFrom<Kind>,
+	{
+		let kind = K::kind_from(self.kind());
+		Error::from_parts(kind, None, Some(Box::new(self)), Location::caller())
+	}
+
+	/// Replaces the kind of this error by applying `op` to it.
+	///
+	/// Unlike [`kind_into`], no new error is created: the inner error, the source, the context
+	/// frames, the attachments, the location and the backtrace are kept.
+	///
+	/// [`kind_into`]: #method.kind_into
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::Error;
+	///
+	/// #[derive(Debug)]
+	/// enum StringKind {
+	///     InvalidUtf8,
+	/// }
+	///
+	/// #[derive(Debug, PartialEq)]
+	/// enum AssetKind {
+	///     Invalid,
+	/// }
+	///
+	/// let error = Error::new(StringKind::InvalidUtf8, "invalid byte sequence");
+	/// let error = error.map_kind(|_| AssetKind::Invalid);
+	///
+	/// assert_eq!(error.kind(), &AssetKind::Invalid);
+	/// assert_eq!(error.get_ref().unwrap().to_string(), "invalid byte sequence");
+	/// ```
+	#[must_use]
+	pub fn map_kind<K, F>(self, op: F) -> Error<K>
+	where
+		F: FnOnce(Kind) -> K,
+	{
+		let Repr {
+			kind,
+			error,
+			source,
+			frames,
+			attachments,
+			location,
+			backtrace,
+		} = *self.repr;
+		Error {
+			repr: Box::new(Repr {
+				kind: op(kind),
+				error,
+				source,
+				frames,
+				attachments,
+				location,
+				backtrace,
+			}),
+		}
+	}
+
+	/// Returns the [`Severity`] of this error as classified by its kind.
+	///
+	/// [`Severity`]: enum.Severity.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// use std::io;
+	///
+	/// use astral::error::{Error, Severity};
+	///
+	/// let error = Error::new(io::ErrorKind::TimedOut, "connection timed out");
+	/// assert_eq!(error.severity(), Severity::Retryable);
+	/// ```
+	#[must_use]
+	pub fn severity(&self) -> Severity
+	where
+		Kind: Classify,
+	{
+		self.kind().severity()
+	}
+
+	/// Returns an iterator over this error and all of its sources.
+	///
+	/// The first element is this error itself, followed by the error returned by
+	/// [`Error::source`], and so on.
+	///
+	/// [`Error::source`]: std::error::Error::source
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use std::fmt::{self, Debug, Display, Formatter};
+	/// # #[derive(Debug)] enum MyErrorKind { Variant }
+	/// # impl Display for MyErrorKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// use astral::error::Error;
+	///
+	/// let root = Error::new(MyErrorKind::Variant, "file not found");
+	/// let error = Error::chained(MyErrorKind::Variant, "could not load texture", root);
+	/// let error = Error::chained(MyErrorKind::Variant, "could not load model", error);
+	///
+	/// let messages = error.chain().map(|e| e.to_string()).collect::<Vec<_>>();
+	/// assert_eq!(messages, [
+	///     "could not load model",
+	///     "could not load texture",
+	///     "file not found"
+	/// ]);
+	/// ```
+	#[must_use]
+	pub fn chain(&self) -> Chain<'_>
+	where
+		Kind: Debug + Display + 'static,
+	{
+		Chain::new(self)
+	}
+
+	/// Returns the backtrace, which was captured when this error was created.
+	///
+	/// A backtrace is only captured, if the `backtrace` feature is enabled and backtraces are
+	/// enabled by setting the `RUST_LIB_BACKTRACE` or `RUST_BACKTRACE` environment variable,
+	/// as described in [`Backtrace`]. Otherwise, this returns `None`.
+	///
+	/// [`Backtrace`]: std::backtrace::Backtrace
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::Error;
+	///
+	/// #[derive(Debug)]
+	/// enum MyErrorKind {
+	///     Variant,
+	/// }
+	///
+	/// std::env::set_var("RUST_LIB_BACKTRACE", "1");
+	///
+	/// let my_error = Error::new(MyErrorKind::Variant, "oh no!");
+	/// if cfg!(feature = "backtrace") {
+	///     assert!(my_error.backtrace().is_some());
+	///     assert!(format!("{:?}", my_error).contains("backtrace"));
+	/// } else {
+	///     assert!(my_error.backtrace().is_none());
+	/// }
+	///
+	/// let my_error = Error::from(MyErrorKind::Variant);
+	/// assert_eq!(my_error.backtrace().is_some(), cfg!(feature = "backtrace"));
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn backtrace(&self) -> Option<&Backtrace> {
+		self.repr.backtrace()
+	}
+
+	/// Returns the location in the source code, where this error was created.
+	///
+	/// The location is tracked through [`Error::new`], [`Error::chained`], `From<Kind>` and the
+	/// methods of [`ResultExt`] and [`OptionExt`], so it points to the code, which created the
+	/// error or attached the context.
+	///
+	/// [`Error::new`]: #method.new
+	/// [`Error::chained`]: #method.chained
+	/// [`ResultExt`]: trait.ResultExt.html
+	/// [`OptionExt`]: trait.OptionExt.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::{Error, OptionExt};
+	///
+	/// #[derive(Debug)]
+	/// enum MyErrorKind {
+	///     Variant,
+	/// }
+	///
+	/// let my_error = Error::new(MyErrorKind::Variant, "oh no!");
+	/// assert_eq!(my_error.location().line(), line!() - 1);
+	///
+	/// let my_error = None::<()>.ok_or_error(MyErrorKind::Variant, "oh no!").unwrap_err();
+	/// assert_eq!(my_error.location().line(), line!() - 1);
+	/// assert_eq!(my_error.location().file(), file!());
+	/// ```
+	#[inline]
+	#[must_use]
+	pub fn location(&self) -> &'static Location<'static> {
+		self.repr.location
+	}
+
+	/// Adds a context frame with the given message on top of this error.
+	///
+	/// Unlike [`Error::chained`], this does not create a new error but pushes a [`Frame`] on the
+	/// stack of this error, so the kind and the root cause are kept. The `Display` implementation
+	/// of `Error` prints the message of the last added frame, while [`Report`] prints all frames.
+	/// For a `Result` with an `Error`, [`FrameResultExt::frame`] does the same.
+	///
+	/// [`Error::chained`]: #method.chained
+	/// [`FrameResultExt::frame`]: trait.FrameResultExt.html#tymethod.frame
+	/// [`Frame`]: struct.Frame.html
+	/// [`Report`]: struct.Report.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use std::fmt::{self, Debug, Display, Formatter};
+	/// # #[derive(Debug)] enum MyErrorKind { Variant }
+	/// # impl Display for MyErrorKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// use astral::error::Error;
+	///
+	/// let error = Error::new(MyErrorKind::Variant, "file not found")
+	///     .context("could not load texture")
+	///     .context("could not load model");
+	///
+	/// assert_eq!(error.to_string(), "could not load model");
+	/// assert_eq!(error.get_ref().unwrap().to_string(), "file not found");
+	/// ```
+	#[must_use]
+	#[track_caller]
+	pub fn context<M>(mut self, message: M) -> Self
+	where
+		M: Display + Send + Sync + 'static,
+	{
+		self.repr
+			.frames
+			.push(Frame::new(Box::new(message), None, Location::caller()));
+		self
+	}
+
+	/// Adds a context frame with the given message and structured data on top of this error.
+	///
+	/// The data can be retrieved with [`Frame::data`].
+	///
+	/// [`Frame::data`]: struct.Frame.html#method.data
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::Error;
+	///
+	/// #[derive(Debug)]
+	/// enum MyErrorKind {
+	///     Variant,
+	/// }
+	///
+	/// #[derive(Debug, PartialEq)]
+	/// struct AssetId(u32);
+	///
+	/// let error = Error::new(MyErrorKind::Variant, "oh no!")
+	///     .context_with_data("could not load asset", AssetId(42));
+	///
+	/// assert_eq!(error.frames()[0].data::<AssetId>(), Some(&AssetId(42)));
+	/// ```
+	#[must_use]
+	#[track_caller]
+	pub fn context_with_data<M, D>(mut self, message: M, data: D) -> Self
+	where
+		M: Display + Send + Sync + 'static,
+		D: Any + Send + Sync,
+	{
+		self.repr.frames.push(Frame::new(
+			Box::new(message),
+			Some(Box::new(data)),
+			Location::caller(),
+		));
+		self
+	}
+
+	/// Returns the context frames of this error in the order they were added.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::Error;
+	///
+	/// #[derive(Debug)]
+	/// enum MyErrorKind {
+	///     Variant,
+	/// }
+	///
+	/// let error = Error::from(MyErrorKind::Variant).context("first").context("second");
+	///
+	/// let messages = error.frames().iter().map(|f| f.to_string()).collect::<Vec<_>>();
+	/// assert_eq!(messages, ["first", "second"]);
+	/// ```
+	#[must_use]
+	pub fn frames(&self) -> &[Frame] {
+		&self.repr.frames
+	}
+
+	/// Attaches a key-value pair to this error.
+	///
+	/// Attachments are printed by the `Debug` implementation and emitted as separate fields, when
+	/// the error is logged with `slog`. The keys `kind`, `message`, `location`, `attachments` and
+	/// `causes` are used by the error itself, so attachments with these keys are emitted in the
+	/// `attachments` field instead.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::Error;
+	///
+	/// #[derive(Debug)]
+	/// enum MyErrorKind {
+	///     NotFound,
+	/// }
+	///
+	/// let error = Error::new(MyErrorKind::NotFound, "file not found")
+	///     .with("path", "models/cube.obj")
+	///     .with("attempts", 3);
+	///
+	/// assert_eq!(error.attachments().len(), 2);
+	/// assert_eq!(error.attachment("path").unwrap().to_string(), "models/cube.obj");
+	/// assert!(error.attachment("size").is_none());
+	/// ```
+	#[must_use]
+	pub fn with<V>(mut self, key: &'static str, value: V) -> Self
+	where
+		V: Display + Send + Sync + 'static,
+	{
+		self.repr
+			.attachments
+			.push(Attachment::new(key, Box::new(value)));
+		self
+	}
+
+	/// Returns the key-value pairs attached to this error in the order they were added.
+	#[must_use]
+	pub fn attachments(&self) -> &[Attachment] {
+		&self.repr.attachments
+	}
+
+	/// Returns the value of the last attachment with the given key.
+	#[must_use]
+	pub fn attachment(&self, key: &str) -> Option<&(dyn Display + Send + Sync)> {
+		self.repr
+			.attachments
+			.iter()
+			.rev()
+			.find(|attachment| attachment.key() == key)
+			.map(Attachment::value)
 	}
 }
 
@@ -398,9 +1100,21 @@ where
 
 impl<Kind> From<Kind> for Error<Kind> {
 	#[inline]
+	#[track_caller]
 	fn from(kind: Kind) -> Self {
-		Self {
-			repr: Repr::Simple(kind),
-		}
+		Self::from_parts(kind, None, None, Location::caller())
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_size() {
+		assert_eq!(size_of::<Error<()>>(), size_of::<usize>());
+		assert_eq!(size_of::<Error<[u64; 8]>>(), size_of::<usize>());
+		assert_eq!(size_of::<Result<(), Error<()>>>(), size_of::<usize>());
+		assert_eq!(size_of::<Option<Error<Severity>>>(), size_of::<usize>());
 	}
 }
@@ -0,0 +1,147 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::fmt::{Debug, Display, Write as _};
+
+use astral_thirdparty::slog::{self, Key, Record, Serializer, Value, KV};
+
+use super::{report, Error};
+
+/// The keys of the fields emitted for every error, which cannot be used by attachments.
+const RESERVED_KEYS: [&str; 5] = ["kind", "message", "location", "attachments", "causes"];
+
+/// Emits the kind, the message, the location, the attachments and the causes of the error as
+/// separate fields.
+///
+/// The causes are joined into one field `causes`, starting with the outermost one and separated by
+/// `: `. Attachments with one of the keys used by this implementation, and attachments with keys,
+/// which are not known at compile time, e.g. the attachments of an error reconstructed from an
+/// [`ErrorReport`], are joined into one field `attachments` as `key=value` pairs, so they cannot
+/// shadow other fields.
+///
+/// [`ErrorReport`]: struct.ErrorReport.html
+///
+/// # Example
+///
+/// ```
+/// # use std::fmt::{self, Debug, Display, Formatter};
+/// # #[derive(Debug)] enum MyErrorKind { NotFound }
+/// # impl Display for MyErrorKind {
+/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// #         Debug::fmt(self, f)
+/// #     }
+/// # }
+/// # impl astral::error::ErrorKind for MyErrorKind {}
+/// # use std::sync::{Arc, Mutex};
+/// use astral::{
+///     error::{Error, ErrorReport},
+///     thirdparty::slog,
+/// };
+/// # struct Recorder(Vec<String>);
+/// # impl slog::Serializer for Recorder {
+/// #     fn emit_arguments(&mut self, key: slog::Key, val: &fmt::Arguments<'_>) -> slog::Result {
+/// #         self.0.push(format!("{}={}", key, val));
+/// #         Ok(())
+/// #     }
+/// # }
+/// # struct Drain(Arc<Mutex<Vec<String>>>);
+/// # impl slog::Drain for Drain {
+/// #     type Ok = ();
+/// #     type Err = slog::Never;
+/// #     fn log(&self, record: &slog::Record<'_>, _: &slog::OwnedKVList) -> Result<(), slog::Never> {
+/// #         let mut recorder = Recorder(Vec::new());
+/// #         slog::KV::serialize(&record.kv(), record, &mut recorder).unwrap();
+/// #         self.0.lock().unwrap().extend(recorder.0);
+/// #         Ok(())
+/// #     }
+/// # }
+/// # let fields = Arc::new(Mutex::new(Vec::new()));
+/// # let logger = slog::Logger::root(Drain(Arc::clone(&fields)), slog::o!());
+///
+/// let error = Error::new(MyErrorKind::NotFound, "file not found")
+///     .context("could not open file")
+///     .context("could not load model")
+///     .with("path", "models/cube.obj")
+///     .with("kind", "mesh");
+///
+/// slog::error!(logger, "load failed"; &error);
+/// # let logged = std::mem::take(&mut *fields.lock().unwrap());
+/// # let location = format!("location={}", error.location());
+/// assert_eq!(logged, [
+///     "kind=NotFound",
+///     "message=could not load model",
+///     &location,
+///     "path=models/cube.obj",
+///     "attachments=kind=mesh",
+///     "causes=could not open file: file not found",
+/// ]);
+///
+/// let remote = ErrorReport::new(&error).into_error();
+/// slog::error!(logger, "load failed"; &remote);
+/// # let logged = std::mem::take(&mut *fields.lock().unwrap());
+/// assert!(logged.contains(&"attachments=path=models/cube.obj, kind=mesh".to_string()));
+///
+/// slog::error!(logger, "load failed"; "error" => &error);
+/// ```
+impl<Kind> KV for Error<Kind>
+where
+	Kind: Debug + Display + 'static,
+{
+	fn serialize(&self, _record: &Record<'_>, serializer: &mut dyn Serializer) -> slog::Result {
+		serializer.emit_arguments("kind", &format_args!("{:?}", self.kind()))?;
+		serializer.emit_arguments("message", &format_args!("{self}"))?;
+		serializer.emit_arguments("location", &format_args!("{}", self.location()))?;
+		let mut attachments = String::new();
+		for attachment in self.attachments() {
+			match attachment.static_key() {
+				Some(key) if !RESERVED_KEYS.contains(&key) => {
+					serializer.emit_arguments(key, &format_args!("{}", attachment.value()))?;
+				}
+				_ => {
+					if !attachments.is_empty() {
+						attachments.push_str(", ");
+					}
+					let _ = write!(attachments, "{}={}", attachment.key(), attachment.value());
+				}
+			}
+		}
+		if !attachments.is_empty() {
+			serializer.emit_str("attachments", &attachments)?;
+		}
+		let causes = report::causes(self)
+			.into_iter()
+			.map(|(cause, _)| cause)
+			.collect::<Vec<_>>();
+		if !causes.is_empty() {
+			serializer.emit_str("causes", &causes.join(": "))?;
+		}
+		Ok(())
+	}
+}
+
+/// Emits the message of the error.
+impl<Kind> Value for Error<Kind>
+where
+	Kind: Display,
+{
+	fn serialize(
+		&self,
+		_record: &Record<'_>,
+		key: Key,
+		serializer: &mut dyn Serializer,
+	) -> slog::Result {
+		serializer.emit_arguments(key, &format_args!("{self}"))
+	}
+}
@@ -0,0 +1,133 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+/// Creates an [`Error`] from a kind and an optional formatted message.
+///
+/// `err!(kind)` is equivalent to `Error::from(kind)`, `err!(kind, "format", args...)` creates the
+/// error with [`Error::new`] and a message formatted by [`format!`]. The location of the error is
+/// the invocation of the macro.
+///
+/// [`Error`]: struct.Error.html
+/// [`Error::new`]: struct.Error.html#method.new
+///
+/// # Example
+///
+/// ```
+/// use astral::error::{err, Error};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyErrorKind {
+///     NotFound,
+/// }
+///
+/// let error: Error<MyErrorKind> = err!(MyErrorKind::NotFound);
+/// assert!(error.get_ref().is_none());
+///
+/// let path = "models/cube.obj";
+/// let error = err!(MyErrorKind::NotFound, "could not find {}", path);
+/// assert_eq!(error.location().line(), line!() - 1);
+/// assert_eq!(error.kind(), &MyErrorKind::NotFound);
+/// assert_eq!(error.get_ref().unwrap().to_string(), "could not find models/cube.obj");
+/// ```
+#[macro_export]
+macro_rules! err {
+	($kind:expr $(,)?) => {
+		$crate::Error::from($kind)
+	};
+	($kind:expr, $($arg:tt)+) => {
+		$crate::Error::new($kind, $crate::__format!($($arg)+))
+	};
+}
+
+/// Returns early with an [`Error`] created by [`err!`].
+///
+/// `bail!(kind, "format", args...)` is equivalent to `return Err(err!(kind, "format", args...))`.
+///
+/// [`Error`]: struct.Error.html
+/// [`err!`]: macro.err.html
+///
+/// # Example
+///
+/// ```
+/// use astral::error::{bail, Error};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyErrorKind {
+///     OutOfRange,
+/// }
+///
+/// fn check(index: usize) -> Result<(), Error<MyErrorKind>> {
+///     if index > 3 {
+///         bail!(MyErrorKind::OutOfRange, "index {} is out of range", index);
+///     }
+///     Ok(())
+/// }
+///
+/// assert!(check(2).is_ok());
+/// let error = check(5).unwrap_err();
+/// assert_eq!(error.kind(), &MyErrorKind::OutOfRange);
+/// assert_eq!(error.get_ref().unwrap().to_string(), "index 5 is out of range");
+/// ```
+#[macro_export]
+macro_rules! bail {
+	($($arg:tt)+) => {
+		return ::core::result::Result::Err($crate::err!($($arg)+))
+	};
+}
+
+/// Returns early with an [`Error`] created by [`err!`], if the condition is not satisfied.
+///
+/// `ensure!(cond, kind, "format", args...)` is equivalent to
+/// `if !cond { bail!(kind, "format", args...) }`. If no message is given, the stringified
+/// condition is used as message.
+///
+/// [`Error`]: struct.Error.html
+/// [`err!`]: macro.err.html
+///
+/// # Example
+///
+/// ```
+/// use astral::error::{ensure, Error};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyErrorKind {
+///     OutOfRange,
+/// }
+///
+/// fn check(index: usize) -> Result<(), Error<MyErrorKind>> {
+///     ensure!(index <= 3, MyErrorKind::OutOfRange);
+///     ensure!(index != 2, MyErrorKind::OutOfRange, "index {} is reserved", index);
+///     Ok(())
+/// }
+///
+/// assert!(check(1).is_ok());
+/// let error = check(5).unwrap_err();
+/// assert_eq!(error.get_ref().unwrap().to_string(), "condition failed: `index <= 3`");
+/// let error = check(2).unwrap_err();
+/// assert_eq!(error.get_ref().unwrap().to_string(), "index 2 is reserved");
+/// ```
+#[macro_export]
+macro_rules! ensure {
+	($cond:expr, $kind:expr $(,)?) => {
+		if !$cond {
+			$crate::bail!($kind, "condition failed: `{}`", stringify!($cond));
+		}
+	};
+	($cond:expr, $kind:expr, $($arg:tt)+) => {
+		if !$cond {
+			$crate::bail!($kind, $($arg)+);
+		}
+	};
+}
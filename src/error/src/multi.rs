@@ -0,0 +1,255 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use alloc::{
+	slice,
+	string::ToString,
+	vec::{self, Vec},
+};
+use core::{
+	error,
+	fmt::{self, Debug, Display, Formatter},
+	iter::FromIterator,
+};
+
+use super::Error;
+
+/// A collection of independent errors.
+///
+/// `MultiError` is used, when an operation produces many failures, which should be reported
+/// together, e.g. when loading a directory of assets. It can be built with [`push`], collected
+/// from an iterator of errors, or returned from [`collect_all`]. With [`Error::aggregate`] it can
+/// be wrapped into a single [`Error`].
+///
+/// [`push`]: #method.push
+/// [`collect_all`]: trait.CollectAll.html#tymethod.collect_all
+/// [`Error::aggregate`]: struct.Error.html#method.aggregate
+/// [`Error`]: struct.Error.html
+///
+/// # Example
+///
+/// ```
+/// # use std::fmt::{self, Debug, Display, Formatter};
+/// # #[derive(Debug)] enum MyErrorKind { Variant }
+/// # impl Display for MyErrorKind {
+/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// #         Debug::fmt(self, f)
+/// #     }
+/// # }
+/// use astral::error::{Error, MultiError};
+///
+/// let mut errors = MultiError::new();
+/// errors.push(Error::new(MyErrorKind::Variant, "missing texture"));
+/// errors.push(Error::new(MyErrorKind::Variant, "missing shader"));
+///
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(
+///     errors.to_string(),
+///     "2 errors occurred:\n    0: missing texture\n    1: missing shader"
+/// );
+/// ```
+pub struct MultiError<Kind> {
+	errors: Vec<Error<Kind>>,
+}
+
+impl<Kind> MultiError<Kind> {
+	/// Creates an empty `MultiError`.
+	#[must_use]
+	pub fn new() -> Self {
+		Self { errors: Vec::new() }
+	}
+
+	/// Appends an error.
+	pub fn push(&mut self, error: Error<Kind>) {
+		self.errors.push(error);
+	}
+
+	/// Returns the number of collected errors.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.errors.len()
+	}
+
+	/// Returns `true` if no error was collected.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.errors.is_empty()
+	}
+
+	/// Returns the collected errors.
+	#[must_use]
+	pub fn errors(&self) -> &[Error<Kind>] {
+		&self.errors
+	}
+
+	/// Returns an iterator over the collected errors.
+	pub fn iter(&self) -> slice::Iter<'_, Error<Kind>> {
+		self.errors.iter()
+	}
+
+	/// Consumes the `MultiError`, returning the collected errors.
+	#[must_use]
+	pub fn into_vec(self) -> Vec<Error<Kind>> {
+		self.errors
+	}
+}
+
+impl<Kind> Default for MultiError<Kind> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<Kind> Debug for MultiError<Kind>
+where
+	Kind: Debug,
+{
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.debug_list().entries(&self.errors).finish()
+	}
+}
+
+impl<Kind> Display for MultiError<Kind>
+where
+	Kind: Display,
+{
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		match self.errors.len() {
+			0 => return write!(fmt, "no errors occurred"),
+			1 => write!(fmt, "1 error occurred:")?,
+			len => write!(fmt, "{len} errors occurred:")?,
+		}
+		for (index, error) in self.errors.iter().enumerate() {
+			let message = error.to_string();
+			for (line_index, line) in message.lines().enumerate() {
+				if line_index == 0 {
+					write!(fmt, "\n{index:>5}: {line}")?;
+				} else {
+					write!(fmt, "\n       {line}")?;
+				}
+			}
+		}
+		Ok(())
+	}
+}
+
+impl<Kind> error::Error for MultiError<Kind> where Kind: Debug + Display {}
+
+impl<Kind> From<Vec<Error<Kind>>> for MultiError<Kind> {
+	fn from(errors: Vec<Error<Kind>>) -> Self {
+		Self { errors }
+	}
+}
+
+impl<Kind> FromIterator<Error<Kind>> for MultiError<Kind> {
+	fn from_iter<I>(iter: I) -> Self
+	where
+		I: IntoIterator<Item = Error<Kind>>,
+	{
+		Self {
+			errors: iter.into_iter().collect(),
+		}
+	}
+}
+
+impl<Kind> Extend<Error<Kind>> for MultiError<Kind> {
+	fn extend<I>(&mut self, iter: I)
+	where
+		I: IntoIterator<Item = Error<Kind>>,
+	{
+		self.errors.extend(iter);
+	}
+}
+
+impl<Kind> IntoIterator for MultiError<Kind> {
+	type IntoIter = vec::IntoIter<Error<Kind>>;
+	type Item = Error<Kind>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.errors.into_iter()
+	}
+}
+
+impl<'a, Kind> IntoIterator for &'a MultiError<Kind> {
+	type IntoIter = slice::Iter<'a, Error<Kind>>;
+	type Item = &'a Error<Kind>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// Extension methods for iterators over [`Result`]s.
+pub trait CollectAll<T, Kind> {
+	/// Collects all values, or all errors if at least one element is an `Err`.
+	///
+	/// Unlike collecting into a `Result`, this does not stop at the first error.
+	///
+	/// # Errors
+	///
+	/// Returns a [`MultiError`] with every error of the iterator, if there is at least one.
+	///
+	/// [`MultiError`]: struct.MultiError.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use std::fmt::{self, Debug, Display, Formatter};
+	/// # #[derive(Debug)] enum MyErrorKind { Parse }
+	/// # impl Display for MyErrorKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// use astral::error::{CollectAll, Error};
+	///
+	/// let parse = |s: &str| s.parse::<u32>().map_err(|e| Error::new(MyErrorKind::Parse, e));
+	///
+	/// let values: Vec<u32> = ["1", "2", "3"].iter().map(|s| parse(s)).collect_all().unwrap();
+	/// assert_eq!(values, [1, 2, 3]);
+	///
+	/// let errors = ["1", "x", "y"].iter().map(|s| parse(s)).collect_all::<Vec<_>>().unwrap_err();
+	/// assert_eq!(errors.len(), 2);
+	/// ```
+	fn collect_all<C>(self) -> Result<C, MultiError<Kind>>
+	where
+		C: FromIterator<T>;
+}
+
+impl<I, T, Kind> CollectAll<T, Kind> for I
+where
+	I: Iterator<Item = Result<T, Error<Kind>>>,
+{
+	fn collect_all<C>(self) -> Result<C, MultiError<Kind>>
+	where
+		C: FromIterator<T>,
+	{
+		let mut errors = MultiError::new();
+		let values = self
+			.filter_map(|result| match result {
+				Ok(value) => Some(value),
+				Err(error) => {
+					errors.push(error);
+					None
+				}
+			})
+			.collect();
+		if errors.is_empty() {
+			Ok(values)
+		} else {
+			Err(errors)
+		}
+	}
+}
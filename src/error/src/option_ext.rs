@@ -13,7 +13,8 @@
 // limitations under the License.
 // Written by Tim Diekmann <tim.diekmann@3dvision.de>, November 2018
 
-use std::error;
+use alloc::boxed::Box;
+use core::error;
 
 use super::Error;
 
@@ -81,18 +82,26 @@ pub trait OptionExt<T> {
 }
 
 impl<T> OptionExt<T> for Option<T> {
+	#[track_caller]
 	fn ok_or_error<Kind, Context>(self, kind: Kind, context: Context) -> Result<T, Error<Kind>>
 	where
 		Context: Into<Box<dyn error::Error + Send + Sync>>,
 	{
-		self.ok_or_else(|| Error::new(kind, context))
+		match self {
+			Some(value) => Ok(value),
+			None => Err(Error::new(kind, context)),
+		}
 	}
 
+	#[track_caller]
 	fn ok_or_error_with<Kind, Context, F>(self, kind: Kind, context: F) -> Result<T, Error<Kind>>
 	where
 		Context: Into<Box<dyn error::Error + Send + Sync>>,
 		F: FnOnce() -> Context,
 	{
-		self.ok_or_else(|| Error::new(kind, context()))
+		match self {
+			Some(value) => Ok(value),
+			None => Err(Error::new(kind, context())),
+		}
 	}
 }
@@ -0,0 +1,409 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+//! Crash reports for panics and conversion of panics into errors.
+//!
+//! [`CrashHandler`] installs a panic hook, which writes a [`CrashReport`] into a directory,
+//! optionally including the most recent log records collected by a [`RingDrain`].
+//!
+//! [`catch_unwind`] converts a panic into an [`Error`], so a panicking job, e.g. in a `rayon`
+//! thread pool, does not take down the whole engine.
+//!
+//! [`CrashHandler`]: struct.CrashHandler.html
+//! [`CrashReport`]: struct.CrashReport.html
+//! [`RingDrain`]: struct.RingDrain.html
+//! [`catch_unwind`]: fn.catch_unwind.html
+//! [`Error`]: ../struct.Error.html
+
+use std::{
+	any::Any,
+	backtrace::Backtrace,
+	collections::VecDeque,
+	error,
+	fmt::{self, Display, Formatter, Write},
+	fs::{self, OpenOptions},
+	io::{self, Write as _},
+	panic::{self, Location, PanicHookInfo, UnwindSafe},
+	path::{Path, PathBuf},
+	process,
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		Arc,
+		Mutex,
+		PoisonError,
+	},
+	thread,
+	time::{SystemTime, UNIX_EPOCH},
+};
+
+use astral_thirdparty::slog::{self, Drain, Key, OwnedKVList, Record, Serializer, KV};
+
+use super::Error;
+
+/// A `slog` drain, which keeps the most recent records in memory.
+///
+/// Clones of a `RingDrain` share the same buffer, so one clone can be passed to the logger while
+/// another one is passed to the [`CrashHandler`].
+///
+/// [`CrashHandler`]: struct.CrashHandler.html
+///
+/// # Example
+///
+/// ```
+/// use astral::{error::panic::RingDrain, thirdparty::slog};
+///
+/// let ring = RingDrain::new(2);
+/// let logger = slog::Logger::root(ring.clone(), slog::o!("subsystem" => "asset"));
+///
+/// slog::info!(logger, "loading"; "path" => "models/cube.obj");
+/// slog::warn!(logger, "slow load");
+/// slog::error!(logger, "load failed");
+///
+/// assert_eq!(ring.records(), [
+///     "WARN slow load, subsystem: asset",
+///     "ERRO load failed, subsystem: asset",
+/// ]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RingDrain {
+	capacity: usize,
+	records: Arc<Mutex<VecDeque<String>>>,
+}
+
+impl RingDrain {
+	/// Creates a drain, which keeps at most `capacity` records.
+	#[must_use]
+	pub fn new(capacity: usize) -> Self {
+		Self {
+			capacity,
+			records: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+		}
+	}
+
+	/// Returns the kept records, starting with the oldest one.
+	pub fn records(&self) -> Vec<String> {
+		let records = self.records.lock().unwrap_or_else(PoisonError::into_inner);
+		records.iter().cloned().collect()
+	}
+}
+
+impl Drain for RingDrain {
+	type Err = slog::Never;
+	type Ok = ();
+
+	fn log(&self, record: &Record<'_>, values: &OwnedKVList) -> Result<(), slog::Never> {
+		if self.capacity == 0 {
+			return Ok(());
+		}
+		let mut line = format!("{} {}", record.level().as_short_str(), record.msg());
+		let mut serializer = LineSerializer(&mut line);
+		// Formatting into a `String` cannot fail
+		let _ = record.kv().serialize(record, &mut serializer);
+		let _ = values.serialize(record, &mut serializer);
+
+		let mut records = self.records.lock().unwrap_or_else(PoisonError::into_inner);
+		if records.len() == self.capacity {
+			let _ = records.pop_front();
+		}
+		records.push_back(line);
+		Ok(())
+	}
+}
+
+struct LineSerializer<'a>(&'a mut String);
+
+impl Serializer for LineSerializer<'_> {
+	fn emit_arguments(&mut self, key: Key, value: &fmt::Arguments<'_>) -> slog::Result {
+		write!(self.0, ", {key}: {value}")?;
+		Ok(())
+	}
+}
+
+/// The report of a panic written by the [`CrashHandler`].
+///
+/// [`CrashHandler`]: struct.CrashHandler.html
+#[derive(Debug)]
+pub struct CrashReport {
+	message: String,
+	location: Option<String>,
+	thread: String,
+	backtrace: Backtrace,
+	records: Vec<String>,
+}
+
+impl CrashReport {
+	/// Creates a report of the panic described by `info` on the current thread.
+	///
+	/// The backtrace is always captured, regardless of the environment variables.
+	#[must_use]
+	pub fn new(info: &PanicHookInfo<'_>, records: Vec<String>) -> Self {
+		Self {
+			message: payload_message(info.payload()),
+			location: info.location().map(ToString::to_string),
+			thread: thread::current().name().unwrap_or("<unnamed>").to_string(),
+			backtrace: Backtrace::force_capture(),
+			records,
+		}
+	}
+
+	/// Returns the panic message.
+	#[must_use]
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	/// Returns the location of the panic, if known.
+	#[must_use]
+	pub fn location(&self) -> Option<&str> {
+		self.location.as_deref()
+	}
+
+	/// Returns the name of the panicking thread.
+	#[must_use]
+	pub fn thread(&self) -> &str {
+		&self.thread
+	}
+
+	/// Returns the backtrace of the panic.
+	pub fn backtrace(&self) -> &Backtrace {
+		&self.backtrace
+	}
+
+	/// Returns the log records, which were kept before the panic.
+	#[must_use]
+	pub fn records(&self) -> &[String] {
+		&self.records
+	}
+
+	/// Writes the report into a new file in `directory` and returns its path.
+	///
+	/// The file name consists of the time in nanoseconds, the process id and a process-wide
+	/// counter, so reports of concurrent panics never overwrite each other. An existing file is
+	/// never truncated.
+	///
+	/// # Errors
+	///
+	/// Returns an error, if the directory or the file could not be created.
+	pub fn write_to(&self, directory: &Path) -> io::Result<PathBuf> {
+		static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+		fs::create_dir_all(directory)?;
+		loop {
+			let timestamp = SystemTime::now()
+				.duration_since(UNIX_EPOCH)
+				.map_or(0, |duration| duration.as_nanos());
+			let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+			let path = directory.join(format!("crash-{timestamp}-{}-{count}.txt", process::id()));
+			match OpenOptions::new().write(true).create_new(true).open(&path) {
+				Ok(mut file) => {
+					file.write_all(self.to_string().as_bytes())?;
+					return Ok(path);
+				}
+				// Another report got the same name, try the next one
+				Err(error) if error.kind() == io::ErrorKind::AlreadyExists => {}
+				Err(error) => return Err(error),
+			}
+		}
+	}
+}
+
+impl Display for CrashReport {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		write!(fmt, "thread '{}' panicked", self.thread)?;
+		if let Some(location) = &self.location {
+			write!(fmt, " at {location}")?;
+		}
+		write!(fmt, ":\n{}\n\nBacktrace:\n{}", self.message, self.backtrace)?;
+		if !self.records.is_empty() {
+			write!(fmt, "\n\nRecent log records:")?;
+			for record in &self.records {
+				write!(fmt, "\n{record}")?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// Installs a panic hook, which writes a [`CrashReport`] for every panic.
+///
+/// After the report was written, the previously installed hook is called, so the default
+/// output is kept.
+///
+/// [`CrashReport`]: struct.CrashReport.html
+///
+/// # Example
+///
+/// ```
+/// use std::{env, fs, panic, process};
+///
+/// use astral::{
+///     error::panic::{CrashHandler, RingDrain},
+///     thirdparty::slog,
+/// };
+///
+/// let ring = RingDrain::new(64);
+/// let logger = slog::Logger::root(ring.clone(), slog::o!());
+///
+/// let directory = env::temp_dir().join(format!("astral-crashes-{}", process::id()));
+/// CrashHandler::new(&directory).with_records(ring).install();
+///
+/// slog::info!(logger, "loading level");
+/// let _ = panic::catch_unwind(|| panic!("oh no!"));
+/// let _ = panic::catch_unwind(|| panic!("not again!"));
+///
+/// let reports = fs::read_dir(&directory)
+///     .unwrap()
+///     .map(|entry| fs::read_to_string(entry.unwrap().path()).unwrap())
+///     .collect::<Vec<_>>();
+/// assert_eq!(reports.len(), 2);
+/// let report = reports.iter().find(|report| report.contains("oh no!")).unwrap();
+/// assert!(report.starts_with("thread 'main' panicked at "));
+/// assert!(report.contains("INFO loading level"));
+/// assert!(reports.iter().any(|report| report.contains("not again!")));
+/// # fs::remove_dir_all(directory).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct CrashHandler {
+	directory: PathBuf,
+	records: Option<RingDrain>,
+}
+
+impl CrashHandler {
+	/// Creates a handler, which writes the reports into `directory`.
+	pub fn new<P>(directory: P) -> Self
+	where
+		P: Into<PathBuf>,
+	{
+		Self {
+			directory: directory.into(),
+			records: None,
+		}
+	}
+
+	/// Includes the records of the given drain in the reports.
+	#[must_use]
+	pub fn with_records(mut self, records: RingDrain) -> Self {
+		self.records = Some(records);
+		self
+	}
+
+	/// Installs the handler as panic hook.
+	pub fn install(self) {
+		let previous = panic::take_hook();
+		panic::set_hook(Box::new(move |info| {
+			let records = self
+				.records
+				.as_ref()
+				.map(RingDrain::records)
+				.unwrap_or_default();
+			let report = CrashReport::new(info, records);
+			if let Err(error) = report.write_to(&self.directory) {
+				eprintln!("failed to write crash report: {error}");
+			}
+			previous(info);
+		}));
+	}
+}
+
+/// The inner error of an [`Error`] created by [`catch_unwind`].
+///
+/// [`Error`]: ../struct.Error.html
+/// [`catch_unwind`]: fn.catch_unwind.html
+#[derive(Debug)]
+pub struct Panic {
+	message: String,
+}
+
+impl Panic {
+	/// Returns the panic message.
+	#[must_use]
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+}
+
+impl Display for Panic {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		write!(fmt, "panicked: {}", self.message)
+	}
+}
+
+impl error::Error for Panic {}
+
+/// Invokes a closure, converting a panic into an [`Error`] of the given kind.
+///
+/// The inner error of the returned error is a [`Panic`] with the panic message. As with
+/// [`std::panic::catch_unwind`], the panic hook is still invoked and panics are only caught, if
+/// the panic strategy is `unwind`.
+///
+/// [`Error`]: ../struct.Error.html
+/// [`Panic`]: struct.Panic.html
+///
+/// # Errors
+///
+/// Returns an error, if the closure panics.
+///
+/// # Example
+///
+/// ```
+/// use astral::{
+///     error::{panic, Error},
+///     thirdparty::rayon::prelude::*,
+/// };
+///
+/// #[derive(Debug, PartialEq)]
+/// enum JobKind {
+///     Panicked,
+/// }
+///
+/// # std::panic::set_hook(Box::new(|_| {}));
+/// let results = (0..4)
+///     .into_par_iter()
+///     .map(|i| {
+///         panic::catch_unwind(JobKind::Panicked, || {
+///             assert!(i != 2, "job {} failed", i);
+///             i * 2
+///         })
+///     })
+///     .collect::<Vec<Result<_, Error<_>>>>();
+///
+/// assert_eq!(results[1].as_ref().unwrap(), &2);
+/// let error = results[2].as_ref().unwrap_err();
+/// assert_eq!(error.kind(), &JobKind::Panicked);
+/// assert_eq!(error.downcast_ref::<panic::Panic>().unwrap().message(), "job 2 failed");
+/// ```
+#[track_caller]
+pub fn catch_unwind<F, T, Kind>(kind: Kind, f: F) -> Result<T, Error<Kind>>
+where
+	F: FnOnce() -> T + UnwindSafe,
+{
+	let location = Location::caller();
+	panic::catch_unwind(f).map_err(|payload| {
+		let panic = Panic {
+			message: payload_message(payload.as_ref()),
+		};
+		Error::from_parts(kind, Some(Box::new(panic)), None, location)
+	})
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+	if let Some(message) = payload.downcast_ref::<&str>() {
+		(*message).to_string()
+	} else if let Some(message) = payload.downcast_ref::<String>() {
+		message.clone()
+	} else {
+		"Box<dyn Any>".to_string()
+	}
+}
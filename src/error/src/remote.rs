@@ -0,0 +1,396 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{
+	any::Any,
+	error,
+	fmt::{self, Display, Formatter},
+	panic::Location,
+};
+
+use astral_thirdparty::serde::{Deserialize, Serialize};
+
+use super::{report, Attachment, Classify, Error, ErrorKind};
+
+/// A serializable snapshot of an [`Error`] with its full context.
+///
+/// An `ErrorReport` is used to send an error to another process, e.g. from a tool to the editor,
+/// or to store it in a crash dump. It contains the kind as string and its code, the message, the
+/// location, the attachments, the causes and the backtrace. With [`into_error`], it is
+/// reconstructed as [`Error<RemoteKind>`], which keeps the attachments and is printed by [`Report`]
+/// with the remote locations and backtrace.
+///
+/// The `ErrorReport` itself implements [`std::error::Error`]: it displays its message and the
+/// causes are returned as sources.
+///
+/// [`Error`]: struct.Error.html
+/// [`into_error`]: #method.into_error
+/// [`Error<RemoteKind>`]: struct.RemoteKind.html
+/// [`Report`]: struct.Report.html
+///
+/// # Example
+///
+/// ```
+/// use astral::{
+///     error::{Error, ErrorKind, ErrorReport, Report},
+///     thirdparty::serde::{de::DeserializeOwned, Serialize},
+/// };
+///
+/// #[derive(Debug)]
+/// enum AssetKind {
+///     NotFound,
+/// }
+/// # impl std::fmt::Display for AssetKind {
+/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// #         std::fmt::Debug::fmt(self, f)
+/// #     }
+/// # }
+///
+/// impl ErrorKind for AssetKind {
+///     fn code(&self) -> Option<u32> {
+///         Some(404)
+///     }
+/// }
+///
+/// fn assert_serde<T: Serialize + DeserializeOwned>(_: &T) {}
+///
+/// let error = Error::new(AssetKind::NotFound, "file not found")
+///     .context("could not load model")
+///     .with("path", "models/cube.obj");
+/// let report = ErrorReport::new(&error);
+/// assert_serde(&report);
+///
+/// assert_eq!(report.kind(), "NotFound");
+/// assert_eq!(report.code(), Some(404));
+/// assert_eq!(report.message(), "could not load model");
+/// assert_eq!(report.attachments(), [("path".to_string(), "models/cube.obj".to_string())]);
+///
+/// let remote = report.into_error();
+/// assert_eq!(remote.kind().kind(), "NotFound");
+/// assert_eq!(remote.kind().code(), Some(404));
+/// assert_eq!(
+///     Report::new(remote).to_string(),
+///     "could not load model\n\nCaused by:\n    file not found"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "astral_thirdparty::serde")]
+pub struct ErrorReport {
+	kind: String,
+	code: Option<u32>,
+	message: String,
+	location: String,
+	attachments: Vec<(String, String)>,
+	cause: Option<Box<Cause>>,
+	backtrace: Option<String>,
+}
+
+impl ErrorReport {
+	/// Creates a report of the given error.
+	///
+	/// If the error was reconstructed from an `ErrorReport`, the remote kind, location and
+	/// backtrace are reported, so an error can be forwarded over several processes.
+	#[must_use]
+	pub fn new<Kind>(error: &Error<Kind>) -> Self
+	where
+		Kind: ErrorKind + 'static,
+	{
+		let mut cause = None;
+		for (message, location) in report::causes(error).into_iter().rev() {
+			cause = Some(Box::new(Cause {
+				message,
+				location,
+				source: cause,
+			}));
+		}
+		let any: &dyn Any = error.kind();
+		let kind = any
+			.downcast_ref::<RemoteKind>()
+			.map_or_else(|| format!("{:?}", error.kind()), |kind| kind.kind.clone());
+		let backtrace = error.backtrace().map(ToString::to_string).or_else(|| {
+			report::remote(error)
+				.and_then(Self::backtrace)
+				.map(ToString::to_string)
+		});
+		Self {
+			kind,
+			code: error.kind().code(),
+			message: error.to_string(),
+			location: error.frames().last().map_or_else(
+				|| report::root_location(error),
+				|frame| frame.location().to_string(),
+			),
+			attachments: error
+				.attachments()
+				.iter()
+				.map(|attachment| (attachment.key().to_string(), attachment.value().to_string()))
+				.collect(),
+			cause,
+			backtrace,
+		}
+	}
+
+	/// Returns the kind of the reported error as formatted by `Debug`.
+	#[must_use]
+	pub fn kind(&self) -> &str {
+		&self.kind
+	}
+
+	/// Returns the code of the kind of the reported error.
+	#[must_use]
+	pub fn code(&self) -> Option<u32> {
+		self.code
+	}
+
+	/// Returns the message of the reported error.
+	#[must_use]
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	/// Returns the location, where the message of the reported error was created.
+	///
+	/// This is the location of the outermost context, or the location of the error itself, if it
+	/// has no context.
+	#[must_use]
+	pub fn location(&self) -> &str {
+		&self.location
+	}
+
+	/// Returns the attachments of the reported error.
+	#[must_use]
+	pub fn attachments(&self) -> &[(String, String)] {
+		&self.attachments
+	}
+
+	/// Returns the causes of the reported error, starting with the outermost one.
+	pub fn causes(&self) -> impl Iterator<Item = &Cause> {
+		let mut next = self.cause.as_deref();
+		std::iter::from_fn(move || {
+			let cause = next?;
+			next = cause.source.as_deref();
+			Some(cause)
+		})
+	}
+
+	/// Returns the backtrace of the reported error, if one was captured.
+	#[must_use]
+	pub fn backtrace(&self) -> Option<&str> {
+		self.backtrace.as_deref()
+	}
+
+	/// Reconstructs the reported error.
+	///
+	/// The attachments are added to the returned error. No local backtrace is captured, and
+	/// [`Report`] prints the remote location, the locations of the causes and the remote backtrace.
+	/// [`Error::location`] still returns the location, where the error was reconstructed. The
+	/// report itself is stored as inner error and is available by [`Error::downcast_ref`].
+	/// Reporting the returned error with [`new`] yields the same report again.
+	///
+	/// [`Report`]: struct.Report.html
+	/// [`Error::location`]: struct.Error.html#method.location
+	/// [`Error::downcast_ref`]: struct.Error.html#method.downcast_ref
+	/// [`new`]: #method.new
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::{Error, ErrorReport, Report};
+	///
+	/// # #[derive(Debug)]
+	/// # struct AssetKind;
+	/// # impl std::fmt::Display for AssetKind {
+	/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// #         std::fmt::Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// # impl astral::error::ErrorKind for AssetKind {}
+	/// let (error, line) = (Error::new(AssetKind, "file not found"), line!());
+	/// let report = ErrorReport::new(&error.with("path", "models/cube.obj"));
+	///
+	/// let (remote, local) = (report.into_error(), line!());
+	/// assert_eq!(remote.attachments()[0].key(), "path");
+	/// assert_eq!(remote.attachments()[0].value().to_string(), "models/cube.obj");
+	/// assert!(remote.backtrace().is_none());
+	///
+	/// let printed = Report::new(remote).with_locations(true).to_string();
+	/// assert!(printed.contains(&format!("at {}:{}:", file!(), line)));
+	/// assert!(!printed.contains(&format!("at {}:{}:", file!(), local)));
+	/// ```
+	#[must_use]
+	#[track_caller]
+	pub fn into_error(self) -> Error<RemoteKind> {
+		let kind = RemoteKind {
+			kind: self.kind.clone(),
+			code: self.code,
+		};
+		let attachments = self
+			.attachments
+			.iter()
+			.map(|(key, value)| Attachment::new(key.clone(), Box::new(value.clone())))
+			.collect();
+		let mut error = Error::from_parts_with_backtrace(
+			kind,
+			Some(Box::new(self)),
+			None,
+			Location::caller(),
+			None,
+		);
+		error.repr.attachments = attachments;
+		error
+	}
+}
+
+impl<Kind> From<&Error<Kind>> for ErrorReport
+where
+	Kind: ErrorKind + 'static,
+{
+	fn from(error: &Error<Kind>) -> Self {
+		Self::new(error)
+	}
+}
+
+impl Display for ErrorReport {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.write_str(&self.message)
+	}
+}
+
+impl error::Error for ErrorReport {
+	fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+		match &self.cause {
+			Some(cause) => Some(cause.as_ref()),
+			None => None,
+		}
+	}
+}
+
+/// A cause of an [`ErrorReport`].
+///
+/// [`ErrorReport`]: struct.ErrorReport.html
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(crate = "astral_thirdparty::serde")]
+pub struct Cause {
+	message: String,
+	location: Option<String>,
+	source: Option<Box<Cause>>,
+}
+
+impl Cause {
+	/// Returns the message of the cause.
+	#[must_use]
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	/// Returns the location, where the cause was created, if known.
+	#[must_use]
+	pub fn location(&self) -> Option<&str> {
+		self.location.as_deref()
+	}
+}
+
+impl Display for Cause {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.write_str(&self.message)
+	}
+}
+
+impl error::Error for Cause {
+	fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+		match &self.source {
+			Some(source) => Some(source.as_ref()),
+			None => None,
+		}
+	}
+}
+
+/// The kind of an error reconstructed from an [`ErrorReport`].
+///
+/// [`ErrorReport`]: struct.ErrorReport.html
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(crate = "astral_thirdparty::serde")]
+pub struct RemoteKind {
+	kind: String,
+	code: Option<u32>,
+}
+
+impl RemoteKind {
+	/// Returns the kind of the original error as formatted by `Debug`.
+	#[must_use]
+	pub fn kind(&self) -> &str {
+		&self.kind
+	}
+}
+
+impl Display for RemoteKind {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.write_str(&self.kind)
+	}
+}
+
+impl ErrorKind for RemoteKind {
+	fn code(&self) -> Option<u32> {
+		self.code
+	}
+}
+
+impl Classify for RemoteKind {}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+	use crate::Report;
+
+	fn report() -> ErrorReport {
+		ErrorReport {
+			kind: "NotFound".to_string(),
+			code: Some(404),
+			message: "could not load model".to_string(),
+			location: "models.rs:1:1".to_string(),
+			attachments: vec![("path".to_string(), "models/cube.obj".to_string())],
+			cause: Some(Box::new(Cause {
+				message: "file not found".to_string(),
+				location: Some("files.rs:2:2".to_string()),
+				source: None,
+			})),
+			backtrace: Some("0: remote::main".to_string()),
+		}
+	}
+
+	#[test]
+	fn test_round_trip() {
+		let report = report();
+		let error = report.clone().into_error();
+		assert!(error.backtrace().is_none());
+		assert_eq!(ErrorReport::new(&error).attachments(), report.attachments());
+		assert_eq!(
+			Report::new(error)
+				.with_locations(true)
+				.with_backtrace(true)
+				.to_string(),
+			"could not load model\n    at models.rs:1:1\n\nCaused by:\n    file not \
+			 found\n    at files.rs:2:2\n\nBacktrace:\n0: remote::main"
+		);
+	}
+
+	#[test]
+	fn test_forward() {
+		let report = report();
+		let forwarded = ErrorReport::new(&report.clone().into_error());
+		assert_eq!(forwarded, report);
+		assert_eq!(ErrorReport::new(&forwarded.into_error()), report);
+	}
+}
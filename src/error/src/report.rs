@@ -0,0 +1,331 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use alloc::{
+	string::{String, ToString},
+	vec::Vec,
+};
+use core::fmt::{self, Debug, Display, Formatter};
+
+#[cfg(feature = "std")]
+use super::{Cause, ErrorReport};
+use super::Error;
+
+/// A wrapper around [`Error`], which prints the error with all of its causes.
+///
+/// The `Display` and `Debug` implementations print the error message followed by an indented list
+/// of the context frames and the source errors. Optionally, the kind of the error and its [`backtrace`] are printed as
+/// well.
+///
+/// Since `Debug` prints the full report, `Report` can be returned from `main` in a [`Result`]. It
+/// can be created from an `Error` with the `?` operator.
+///
+/// [`Error`]: struct.Error.html
+/// [`backtrace`]: struct.Error.html#method.backtrace
+/// [`Result`]: std::result::Result
+///
+/// # Example
+///
+/// ```
+/// use std::fmt::{self, Debug, Display, Formatter};
+///
+/// use astral::error::{Error, Report};
+///
+/// #[derive(Debug)]
+/// enum MyErrorKind {
+///     Variant,
+/// }
+///
+/// impl Display for MyErrorKind {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         Debug::fmt(self, f)
+///     }
+/// }
+///
+/// let source = Error::new(MyErrorKind::Variant, "file not found");
+/// let error = Error::chained(MyErrorKind::Variant, "could not load asset", source);
+///
+/// let report = Report::new(error);
+/// assert_eq!(
+///     report.to_string(),
+///     "could not load asset\n\nCaused by:\n    file not found"
+/// );
+///
+/// let report = report.with_kind(true);
+/// assert!(report.to_string().starts_with("Variant: could not load asset"));
+///
+/// let error = Error::chained(MyErrorKind::Variant, "could not load level", report.into_inner());
+/// assert_eq!(
+///     Report::new(error).to_string(),
+///     "could not load level\n\nCaused by:\n    0: could not load asset\n    1: file not found"
+/// );
+///
+/// let error = Error::new(MyErrorKind::Variant, "file not found")
+///     .context("could not load texture")
+///     .context("could not load model");
+/// assert_eq!(
+///     Report::new(error).to_string(),
+///     "could not load model\n\nCaused by:\n    0: could not load texture\n    1: file not found"
+/// );
+/// ```
+///
+/// `Report` can be used as error type of `main`:
+///
+/// ```should_panic
+/// # use std::fmt::{self, Debug, Display, Formatter};
+/// use astral::error::{Error, Report};
+///
+/// #[derive(Debug)]
+/// enum MyErrorKind {
+///     Variant,
+/// }
+/// # impl Display for MyErrorKind {
+/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// #         Debug::fmt(self, f)
+/// #     }
+/// # }
+///
+/// fn app() -> Result<(), Error<MyErrorKind>> {
+///     Err(Error::new(MyErrorKind::Variant, "oh no!"))
+/// }
+///
+/// fn main() -> Result<(), Report<MyErrorKind>> {
+///     app()?;
+///     Ok(())
+/// }
+/// ```
+pub struct Report<Kind> {
+	error: Error<Kind>,
+	kind: bool,
+	locations: bool,
+	backtrace: bool,
+}
+
+impl<Kind> Report<Kind> {
+	/// Creates a report for the given error.
+	///
+	/// By default, neither the kind, the locations nor the backtrace are printed.
+	#[must_use]
+	pub fn new(error: Error<Kind>) -> Self {
+		Self {
+			error,
+			kind: false,
+			locations: false,
+			backtrace: false,
+		}
+	}
+
+	/// Sets whether the kind of the error is printed in front of the message.
+	#[must_use]
+	pub fn with_kind(mut self, kind: bool) -> Self {
+		self.kind = kind;
+		self
+	}
+
+	/// Sets whether the [`location`] of the error and its sources is printed.
+	///
+	/// Locations are only known for sources of the same type `Error<Kind>`.
+	///
+	/// [`location`]: struct.Error.html#method.location
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::{Error, Report};
+	///
+	/// #[derive(Debug)]
+	/// enum MyErrorKind {
+	///     Variant,
+	/// }
+	/// # impl std::fmt::Display for MyErrorKind {
+	/// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// #         std::fmt::Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	///
+	/// let error = Error::new(MyErrorKind::Variant, "oh no!");
+	/// let line = line!() - 1;
+	/// let report = Report::new(error).with_locations(true);
+	/// assert_eq!(report.to_string(), format!("oh no!\n    at {}:{}:13", file!(), line));
+	/// ```
+	#[must_use]
+	pub fn with_locations(mut self, locations: bool) -> Self {
+		self.locations = locations;
+		self
+	}
+
+	/// Sets whether the backtrace of the error is printed, if one was captured.
+	#[must_use]
+	pub fn with_backtrace(mut self, backtrace: bool) -> Self {
+		self.backtrace = backtrace;
+		self
+	}
+
+	/// Returns a reference to the reported error.
+	#[must_use]
+	pub fn error(&self) -> &Error<Kind> {
+		&self.error
+	}
+
+	/// Consumes the `Report`, returning the reported error.
+	#[must_use]
+	pub fn into_inner(self) -> Error<Kind> {
+		self.error
+	}
+}
+
+impl<Kind> Display for Report<Kind>
+where
+	Kind: Debug + Display + 'static,
+{
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt_report(&self.error, fmt, self.kind, self.locations, self.backtrace)
+	}
+}
+
+impl<Kind> Debug for Report<Kind>
+where
+	Kind: Debug + Display + 'static,
+{
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(self, fmt)
+	}
+}
+
+impl<Kind> From<Error<Kind>> for Report<Kind> {
+	fn from(error: Error<Kind>) -> Self {
+		Self::new(error)
+	}
+}
+
+/// Writes the report of `error`, optionally with its kind, the locations and the backtrace.
+pub(super) fn fmt_report<Kind>(
+	error: &Error<Kind>,
+	fmt: &mut Formatter<'_>,
+	kind: bool,
+	locations: bool,
+	backtrace: bool,
+) -> fmt::Result
+where
+	Kind: Debug + Display + 'static,
+{
+	if kind && error.get_ref().is_some() {
+		write!(fmt, "{}: ", error.kind())?;
+	}
+	Display::fmt(error, fmt)?;
+	if locations {
+		match error.frames().last() {
+			Some(frame) => write!(fmt, "\n    at {}", frame.location())?,
+			None => write!(fmt, "\n    at {}", root_location(error))?,
+		}
+	}
+
+	let causes = causes(error);
+	if !causes.is_empty() {
+		write!(fmt, "\n\nCaused by:")?;
+		let indent = if causes.len() == 1 { 4 } else { 7 };
+		for (index, (message, location)) in causes.iter().enumerate() {
+			if causes.len() == 1 {
+				write!(fmt, "\n    ")?;
+			} else {
+				write!(fmt, "\n{index:>5}: ")?;
+			}
+			for (line_index, line) in message.lines().enumerate() {
+				if line_index > 0 {
+					write!(fmt, "\n{:indent$}", "")?;
+				}
+				write!(fmt, "{line}")?;
+			}
+			if locations {
+				if let Some(location) = location {
+					write!(fmt, "\n{:indent$}at {location}", "")?;
+				}
+			}
+		}
+	}
+
+	if backtrace {
+		if let Some(backtrace) = error.backtrace() {
+			write!(fmt, "\n\nBacktrace:\n{backtrace}")?;
+		}
+		#[cfg(feature = "std")]
+		{
+			if let Some(backtrace) = remote(error).and_then(ErrorReport::backtrace) {
+				write!(fmt, "\n\nBacktrace:\n{backtrace}")?;
+			}
+		}
+	}
+	Ok(())
+}
+
+/// Returns the messages and locations of the causes of `error`: the context frames below the
+/// outermost one, the root cause and the sources.
+pub(super) fn causes<Kind>(error: &Error<Kind>) -> Vec<(String, Option<String>)>
+where
+	Kind: Debug + Display + 'static,
+{
+	let frames = error.frames();
+	let mut causes = frames
+		.iter()
+		.rev()
+		.skip(1)
+		.map(|frame| (frame.to_string(), Some(frame.location().to_string())))
+		.collect::<Vec<_>>();
+	if !frames.is_empty() {
+		causes.push((Root(error).to_string(), Some(root_location(error))));
+	}
+	causes.extend(error.chain().skip(1).map(|source| {
+		let location = source.downcast_ref::<Error<Kind>>().map(root_location);
+		#[cfg(feature = "std")]
+		let location = location.or_else(|| {
+			source
+				.downcast_ref::<Cause>()
+				.and_then(Cause::location)
+				.map(ToString::to_string)
+		});
+		(source.to_string(), location)
+	}));
+	causes
+}
+
+/// Returns the location of the root of `error`, which is the remote location, if `error` was
+/// reconstructed from an [`ErrorReport`].
+pub(super) fn root_location<Kind>(error: &Error<Kind>) -> String {
+	#[cfg(feature = "std")]
+	{
+		if let Some(report) = remote(error) {
+			return report.location().to_string();
+		}
+	}
+	error.location().to_string()
+}
+
+/// Returns the [`ErrorReport`], if `error` was reconstructed from one.
+#[cfg(feature = "std")]
+pub(super) fn remote<Kind>(error: &Error<Kind>) -> Option<&ErrorReport> {
+	error.get_ref()?.downcast_ref()
+}
+
+/// Displays the root of an error, ignoring its context frames.
+struct Root<'a, Kind>(&'a Error<Kind>);
+
+impl<Kind> Display for Root<'_, Kind>
+where
+	Kind: Display,
+{
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		self.0.repr.fmt_root(fmt)
+	}
+}
@@ -13,50 +13,55 @@
 // limitations under the License.
 // Written by Tim Diekmann <tim.diekmann@3dvision.de>, November 2018
 
-use std::{
+use alloc::{boxed::Box, vec::Vec};
+use core::{
 	error,
 	fmt::{self, Debug, Display, Formatter},
+	panic::Location,
 };
 
-use super::{Chained, Custom};
+use super::{Attachment, Backtrace, Frame};
 
-#[allow(variant_size_differences)]
-pub(super) enum Repr<Kind> {
-	Simple(Kind),
-	Custom(Box<Custom<Kind>>),
-	Chained(Box<Chained<Kind>>),
+/// The representation of an `Error`: a kind and an optional root cause with a stack of context
+/// frames on top of it and key-value attachments.
+pub(super) struct Repr<Kind> {
+	pub(super) kind: Kind,
+	pub(super) error: Option<Box<dyn error::Error + Send + Sync>>,
+	pub(super) source: Option<Box<dyn error::Error + Send + Sync>>,
+	pub(super) frames: Vec<Frame>,
+	pub(super) attachments: Vec<Attachment>,
+	pub(super) location: &'static Location<'static>,
+	pub(super) backtrace: Option<Backtrace>,
 }
 
 impl<Kind> Repr<Kind> {
 	pub(super) fn get_ref(&self) -> Option<&(dyn error::Error + Send + Sync + 'static)> {
-		match self {
-			Repr::Simple(..) => None,
-			Repr::Custom(c) => Some(c.error.as_ref()),
-			Repr::Chained(c) => Some(c.error.as_ref()),
-		}
+		self.error.as_deref()
 	}
 
 	pub(super) fn get_mut(&mut self) -> Option<&mut (dyn error::Error + Send + Sync + 'static)> {
-		match self {
-			Repr::Simple(..) => None,
-			Repr::Custom(c) => Some(c.error.as_mut()),
-			Repr::Chained(c) => Some(c.error.as_mut()),
+		match &mut self.error {
+			Some(error) => Some(error.as_mut()),
+			None => None,
 		}
 	}
 
 	pub(super) fn into_inner(self) -> Option<Box<dyn error::Error + Send + Sync>> {
-		match self {
-			Repr::Simple(..) => None,
-			Repr::Custom(c) => Some(c.error),
-			Repr::Chained(c) => Some(c.error),
-		}
+		self.error
+	}
+
+	pub(super) fn backtrace(&self) -> Option<&Backtrace> {
+		self.backtrace.as_ref()
 	}
 
-	pub(super) fn kind(&self) -> &Kind {
-		match self {
-			Repr::Simple(ref kind) => kind,
-			Repr::Custom(ref c) => &c.kind,
-			Repr::Chained(ref c) => &c.kind,
+	/// Formats the root cause without the context frames.
+	pub(super) fn fmt_root(&self, fmt: &mut Formatter<'_>) -> fmt::Result
+	where
+		Kind: Display,
+	{
+		match &self.error {
+			Some(error) => Display::fmt(error, fmt),
+			None => Display::fmt(&self.kind, fmt),
 		}
 	}
 }
@@ -66,11 +71,25 @@ where
 	Kind: Debug,
 {
 	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-		match *self {
-			Repr::Simple(ref kind) => fmt.debug_tuple("Kind").field(&kind).finish(),
-			Repr::Custom(ref c) => Debug::fmt(&c, fmt),
-			Repr::Chained(ref c) => Debug::fmt(&c, fmt),
+		let mut debug = fmt.debug_struct("Error");
+		let _ = debug.field("kind", &self.kind);
+		if let Some(error) = &self.error {
+			let _ = debug.field("error", error);
+		}
+		if let Some(source) = &self.source {
+			let _ = debug.field("source", source);
+		}
+		if !self.frames.is_empty() {
+			let _ = debug.field("frames", &self.frames);
+		}
+		if !self.attachments.is_empty() {
+			let _ = debug.field("attachments", &self.attachments);
+		}
+		let _ = debug.field("location", &format_args!("{}", self.location));
+		if let Some(backtrace) = &self.backtrace {
+			let _ = debug.field("backtrace", backtrace);
 		}
+		debug.finish()
 	}
 }
 
@@ -79,10 +98,9 @@ where
 	Kind: Display,
 {
 	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
-		match self {
-			Repr::Simple(kind) => Display::fmt(&kind, fmt),
-			Repr::Custom(ref c) => Display::fmt(&c.error, fmt),
-			Repr::Chained(ref c) => Display::fmt(&c.error, fmt),
+		match self.frames.last() {
+			Some(frame) => Display::fmt(frame, fmt),
+			None => self.fmt_root(fmt),
 		}
 	}
 }
@@ -92,10 +110,10 @@ where
 	Kind: Debug + Display,
 {
 	fn source(&self) -> Option<&(dyn error::Error + 'static)> {
-		match self {
-			Repr::Simple(..) => None,
-			Repr::Custom(ref c) => c.error.source(),
-			Repr::Chained(ref c) => Some(c.source.as_ref()),
+		match (&self.source, &self.error) {
+			(Some(source), _) => Some(source.as_ref()),
+			(None, Some(error)) => error.source(),
+			(None, None) => None,
 		}
 	}
 }
@@ -13,7 +13,7 @@
 // limitations under the License.
 // Written by Tim Diekmann <tim.diekmann@3dvision.de>, November 2018
 
-use std::result;
+use core::result;
 
 use super::Error;
 
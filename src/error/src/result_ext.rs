@@ -13,14 +13,25 @@
 // limitations under the License.
 // Written by Tim Diekmann <tim.diekmann@3dvision.de>, November 2018
 
-use std::error;
+use alloc::boxed::Box;
+use core::{error, fmt::Display};
+#[cfg(feature = "std")]
+use std::{io, path::Path};
 
 use super::Error;
+#[cfg(feature = "std")]
+use super::IoKind;
 
 /// Extension methods for [`Result`].
 pub trait ResultExt<T, E> {
 	/// Associates the error with an error kind.
 	///
+	/// This wraps the error into a new [`Error`]. To add a message on top of an existing `Error`
+	/// without wrapping it, use [`FrameResultExt::frame`] instead.
+	///
+	/// [`Error`]: struct.Error.html
+	/// [`FrameResultExt::frame`]: trait.FrameResultExt.html#tymethod.frame
+	///
 	/// # Example
 	///
 	/// ```
@@ -141,6 +152,35 @@ pub trait ResultExt<T, E> {
 	where
 		Source: Into<Box<dyn error::Error + Send + Sync>>,
 		F: FnOnce() -> Source;
+
+	/// Converts an I/O error into an [`Error`] with the corresponding [`IoKind`] and attaches
+	/// `path` with the key `"path"`.
+	///
+	/// Only available with the `std` feature.
+	///
+	/// [`Error`]: struct.Error.html
+	/// [`IoKind`]: enum.IoKind.html
+	///
+	/// # Errors
+	///
+	/// Returns the converted error, if `self` is an `Err`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use std::fs;
+	///
+	/// use astral::error::{IoKind, ResultExt};
+	///
+	/// let error = fs::read("does/not/exist.txt").io_context("does/not/exist.txt").unwrap_err();
+	/// assert_eq!(error.kind(), &IoKind::NotFound);
+	/// assert_eq!(error.attachment("path").unwrap().to_string(), "does/not/exist.txt");
+	/// ```
+	#[cfg(feature = "std")]
+	fn io_context<P>(self, path: P) -> Result<T, Error<IoKind>>
+	where
+		E: Into<io::Error>,
+		P: AsRef<Path>;
 }
 
 #[allow(clippy::use_self)]
@@ -148,22 +188,130 @@ impl<T, E> ResultExt<T, E> for Result<T, E>
 where
 	E: Into<Box<dyn error::Error + Send + Sync>>,
 {
+	#[track_caller]
 	fn context<Kind>(self, kind: Kind) -> Result<T, Error<Kind>> {
-		self.map_err(|error| Error::new(kind, error))
+		match self {
+			Ok(value) => Ok(value),
+			Err(error) => Err(Error::new(kind, error)),
+		}
 	}
 
+	#[track_caller]
 	fn chain<Kind, Source>(self, kind: Kind, source: Source) -> Result<T, Error<Kind>>
 	where
 		Source: Into<Box<dyn error::Error + Send + Sync>>,
 	{
-		self.map_err(|s| Error::chained(kind, source.into(), s))
+		match self {
+			Ok(value) => Ok(value),
+			Err(s) => Err(Error::chained(kind, source.into(), s)),
+		}
 	}
 
+	#[track_caller]
 	fn chain_with<Kind, Source, F>(self, kind: Kind, source: F) -> Result<T, Error<Kind>>
 	where
 		Source: Into<Box<dyn error::Error + Send + Sync>>,
 		F: FnOnce() -> Source,
 	{
-		self.map_err(|s| Error::chained(kind, source(), s.into()))
+		match self {
+			Ok(value) => Ok(value),
+			Err(s) => Err(Error::chained(kind, source(), s.into())),
+		}
+	}
+
+	#[cfg(feature = "std")]
+	#[track_caller]
+	fn io_context<P>(self, path: P) -> Result<T, Error<IoKind>>
+	where
+		E: Into<io::Error>,
+		P: AsRef<Path>,
+	{
+		match self {
+			Ok(value) => Ok(value),
+			Err(error) => {
+				let error: io::Error = error.into();
+				let path = path.as_ref().display().to_string();
+				Err(Error::new(IoKind::from(error.kind()), error).with("path", path))
+			}
+		}
+	}
+}
+
+/// Extension methods for [`Result`]s with an [`Error`] to add context frames.
+///
+/// Unlike [`ResultExt::context`], no new error is created, but a [`Frame`] is pushed on the
+/// existing error by [`Error::context`], so the kind and the root cause are kept.
+///
+/// [`Error`]: struct.Error.html
+/// [`ResultExt::context`]: trait.ResultExt.html#tymethod.context
+/// [`Frame`]: struct.Frame.html
+/// [`Error::context`]: struct.Error.html#method.context
+pub trait FrameResultExt<T, Kind> {
+	/// Adds a context frame with the given message on top of the error.
+	///
+	/// # Errors
+	///
+	/// Returns the error with the added frame, if `self` is an `Err`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use std::fmt::{self, Debug, Display, Formatter};
+	/// # #[derive(Debug, PartialEq)] enum MyErrorKind { NotFound }
+	/// # impl Display for MyErrorKind {
+	/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+	/// #         Debug::fmt(self, f)
+	/// #     }
+	/// # }
+	/// use astral::error::{Error, FrameResultExt};
+	///
+	/// fn open() -> Result<(), Error<MyErrorKind>> {
+	///     Err(Error::new(MyErrorKind::NotFound, "file not found"))
+	/// }
+	///
+	/// let error = open().frame("could not load model").unwrap_err();
+	/// assert_eq!(error.kind(), &MyErrorKind::NotFound);
+	/// assert_eq!(error.to_string(), "could not load model");
+	/// assert_eq!(error.frames().len(), 1);
+	/// ```
+	fn frame<M>(self, message: M) -> Result<T, Error<Kind>>
+	where
+		M: Display + Send + Sync + 'static;
+
+	/// Adds a context frame with the message returned by `message` on top of the error.
+	///
+	/// The closure is only called, if `self` is an `Err`.
+	///
+	/// # Errors
+	///
+	/// Returns the error with the added frame, if `self` is an `Err`.
+	fn frame_with<M, F>(self, message: F) -> Result<T, Error<Kind>>
+	where
+		M: Display + Send + Sync + 'static,
+		F: FnOnce() -> M;
+}
+
+impl<T, Kind> FrameResultExt<T, Kind> for Result<T, Error<Kind>> {
+	#[track_caller]
+	fn frame<M>(self, message: M) -> Result<T, Error<Kind>>
+	where
+		M: Display + Send + Sync + 'static,
+	{
+		match self {
+			Ok(value) => Ok(value),
+			Err(error) => Err(error.context(message)),
+		}
+	}
+
+	#[track_caller]
+	fn frame_with<M, F>(self, message: F) -> Result<T, Error<Kind>>
+	where
+		M: Display + Send + Sync + 'static,
+		F: FnOnce() -> M,
+	{
+		match self {
+			Ok(value) => Ok(value),
+			Err(error) => Err(error.context(message())),
+		}
 	}
 }
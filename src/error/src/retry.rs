@@ -0,0 +1,165 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{thread, time::Duration};
+
+use super::{Classify, Error, Severity};
+
+/// Describes, how often and with which delays an operation is retried by [`retry`].
+///
+/// The delay before the first retry is [`delay`], every further delay is multiplied by the
+/// [`backoff`] factor, but never exceeds [`max_delay`].
+///
+/// [`retry`]: fn.retry.html
+/// [`delay`]: #method.with_delay
+/// [`backoff`]: #method.with_backoff
+/// [`max_delay`]: #method.with_max_delay
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+	attempts: u32,
+	delay: Duration,
+	backoff: u32,
+	max_delay: Duration,
+}
+
+impl RetryPolicy {
+	/// Creates a policy, which runs an operation at most `attempts` times without any delay.
+	///
+	/// An `attempts` value of `0` is treated as `1`.
+	#[must_use]
+	pub fn new(attempts: u32) -> Self {
+		Self {
+			attempts: attempts.max(1),
+			delay: Duration::from_secs(0),
+			backoff: 1,
+			max_delay: Duration::from_secs(u64::MAX),
+		}
+	}
+
+	/// Sets the delay before the first retry.
+	#[must_use]
+	pub fn with_delay(mut self, delay: Duration) -> Self {
+		self.delay = delay;
+		self
+	}
+
+	/// Sets the factor, by which the delay is multiplied after each retry.
+	#[must_use]
+	pub fn with_backoff(mut self, backoff: u32) -> Self {
+		self.backoff = backoff;
+		self
+	}
+
+	/// Sets the upper bound of the delay.
+	#[must_use]
+	pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+		self.max_delay = max_delay;
+		self
+	}
+
+	/// Returns the maximum number of attempts.
+	#[must_use]
+	pub fn attempts(&self) -> u32 {
+		self.attempts
+	}
+
+	/// Returns the delay before the retry following the attempt with the given index.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use std::time::Duration;
+	///
+	/// use astral::error::RetryPolicy;
+	///
+	/// let policy = RetryPolicy::new(5)
+	///     .with_delay(Duration::from_millis(10))
+	///     .with_backoff(2)
+	///     .with_max_delay(Duration::from_millis(30));
+	///
+	/// assert_eq!(policy.delay(0), Duration::from_millis(10));
+	/// assert_eq!(policy.delay(1), Duration::from_millis(20));
+	/// assert_eq!(policy.delay(2), Duration::from_millis(30));
+	/// ```
+	#[must_use]
+	pub fn delay(&self, attempt: u32) -> Duration {
+		let factor = self.backoff.checked_pow(attempt).unwrap_or(u32::MAX);
+		self.delay
+			.checked_mul(factor)
+			.map_or(self.max_delay, |delay| delay.min(self.max_delay))
+	}
+}
+
+impl Default for RetryPolicy {
+	/// Three attempts with a delay of 100 ms, which is doubled after each retry.
+	fn default() -> Self {
+		Self::new(3)
+			.with_delay(Duration::from_millis(100))
+			.with_backoff(2)
+	}
+}
+
+/// Runs `op` until it succeeds, fails with an error, which is not [`Retryable`], or the attempts
+/// of the `policy` are exhausted.
+///
+/// Between the attempts, the current thread sleeps as specified by the `policy`.
+///
+/// [`Retryable`]: enum.Severity.html#variant.Retryable
+///
+/// # Errors
+///
+/// Returns the error of the last attempt, if no attempt succeeded.
+///
+/// # Example
+///
+/// ```
+/// use std::io;
+///
+/// use astral::error::{retry, Error, RetryPolicy};
+///
+/// let mut attempts = 0;
+/// let result = retry(&RetryPolicy::new(3), || {
+///     attempts += 1;
+///     if attempts < 3 {
+///         Err(Error::new(io::ErrorKind::Interrupted, "interrupted"))
+///     } else {
+///         Ok(attempts)
+///     }
+/// });
+/// assert_eq!(result.unwrap(), 3);
+///
+/// let mut attempts = 0;
+/// let result = retry(&RetryPolicy::new(3), || -> Result<(), _> {
+///     attempts += 1;
+///     Err(Error::new(io::ErrorKind::InvalidData, "corrupt data"))
+/// });
+/// assert!(result.is_err());
+/// assert_eq!(attempts, 1);
+/// ```
+pub fn retry<T, Kind, F>(policy: &RetryPolicy, mut op: F) -> Result<T, Error<Kind>>
+where
+	Kind: Classify,
+	F: FnMut() -> Result<T, Error<Kind>>,
+{
+	for attempt in 1..policy.attempts {
+		match op() {
+			Err(error) if error.severity() == Severity::Retryable => {
+				thread::sleep(policy.delay(attempt - 1));
+			}
+			result => return result,
+		}
+	}
+	op()
+}
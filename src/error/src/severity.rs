@@ -0,0 +1,104 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use core::fmt::{self, Display, Formatter};
+#[cfg(feature = "std")]
+use std::io;
+
+/// The severity of an error kind as returned by [`Classify::severity`].
+///
+/// [`Classify::severity`]: trait.Classify.html#method.severity
+///
+/// The severity describes, how the caller should react to an error: a recoverable error can be
+/// handled, e.g. by using a fallback, a retryable error may succeed if the operation is repeated,
+/// and a fatal error should abort the operation.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Severity {
+	/// The error can be handled by the caller.
+	#[default]
+	Recoverable,
+	/// The operation may succeed, if it is repeated.
+	Retryable,
+	/// The operation cannot succeed and should be aborted.
+	Fatal,
+}
+
+impl Display for Severity {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Severity::Recoverable => "recoverable",
+			Severity::Retryable => "retryable",
+			Severity::Fatal => "fatal",
+		};
+		fmt.write_str(name)
+	}
+}
+
+/// Classification of error kinds by their [`Severity`].
+///
+/// The default implementation classifies every kind as [`Recoverable`], so a kind can opt in with
+/// an empty `impl Classify for MyErrorKind {}`. Deriving [`ErrorKind`] implements `Classify` as
+/// well. For [`io::ErrorKind`], interrupted and timed out operations are [`Retryable`].
+///
+/// [`Severity`]: enum.Severity.html
+/// [`Recoverable`]: enum.Severity.html#variant.Recoverable
+/// [`Retryable`]: enum.Severity.html#variant.Retryable
+/// [`ErrorKind`]: trait.ErrorKind.html
+/// [`io::ErrorKind`]: std::io::ErrorKind
+///
+/// # Example
+///
+/// ```
+/// use astral::error::{Classify, Error, Severity};
+///
+/// #[derive(Debug)]
+/// enum AssetKind {
+///     Io,
+///     Corrupt,
+/// }
+///
+/// impl Classify for AssetKind {
+///     fn severity(&self) -> Severity {
+///         match self {
+///             AssetKind::Io => Severity::Retryable,
+///             AssetKind::Corrupt => Severity::Fatal,
+///         }
+///     }
+/// }
+///
+/// let error = Error::new(AssetKind::Io, "device busy");
+/// assert_eq!(error.severity(), Severity::Retryable);
+///
+/// let error = Error::new(AssetKind::Corrupt, "invalid header");
+/// assert_eq!(error.severity(), Severity::Fatal);
+/// ```
+pub trait Classify {
+	/// Returns the severity of this kind.
+	fn severity(&self) -> Severity {
+		Severity::default()
+	}
+}
+
+#[cfg(feature = "std")]
+impl Classify for io::ErrorKind {
+	fn severity(&self) -> Severity {
+		match self {
+			io::ErrorKind::Interrupted | io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => {
+				Severity::Retryable
+			}
+			_ => Severity::Recoverable,
+		}
+	}
+}
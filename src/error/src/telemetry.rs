@@ -0,0 +1,416 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+//! Opt-in counters for constructed errors by kind.
+//!
+//! Errors created with [`Error::recorded`] or passed to [`record`] are counted in a global
+//! registry per kind. A [`Snapshot`] of the counters can be taken at any time, e.g. at the end of
+//! a soak test, and a [`Reporter`] periodically logs the most frequent kinds to a `slog` logger.
+//!
+//! Kinds are identified by their type, their [`Hash`] and their [`Debug`] representation, which is
+//! also used to display them. Errors created by any other constructor are not counted.
+//!
+//! Every distinct value of a kind gets its own counter, and counters are only removed by
+//! [`reset`]. Kinds carrying data, e.g. a [`RemoteKind`] or a kind containing a path, let the
+//! registry grow without bound, so only record such kinds, if the number of values is limited.
+//!
+//! [`Error::recorded`]: ../struct.Error.html#method.recorded
+//! [`record`]: fn.record.html
+//! [`reset`]: fn.reset.html
+//! [`RemoteKind`]: ../struct.RemoteKind.html
+//! [`Snapshot`]: struct.Snapshot.html
+//! [`Reporter`]: struct.Reporter.html
+//! [`Hash`]: std::hash::Hash
+//! [`Debug`]: std::fmt::Debug
+//!
+//! # Example
+//!
+//! ```
+//! use astral::error::{telemetry, Error};
+//!
+//! #[derive(Debug, Hash)]
+//! enum MyErrorKind {
+//!     NotFound,
+//!     InvalidAsset,
+//! }
+//!
+//! for path in &["cube.obj", "sphere.obj"] {
+//!     let _error = Error::recorded(MyErrorKind::NotFound, format!("{} not found", path));
+//! }
+//! let _error = Error::recorded(MyErrorKind::InvalidAsset, "corrupt mesh");
+//!
+//! let snapshot = telemetry::snapshot();
+//! assert_eq!(snapshot.total(), 3);
+//! assert_eq!(snapshot.count(&MyErrorKind::NotFound), 2);
+//! assert_eq!(snapshot.top(1)[0].kind(), "NotFound");
+//! ```
+
+use std::{
+	any::{self, TypeId},
+	collections::{hash_map::DefaultHasher, BTreeMap},
+	error,
+	fmt::{self, Debug, Display, Formatter},
+	hash::{Hash, Hasher},
+	io,
+	slice,
+	sync::{
+		mpsc::{self, RecvTimeoutError, Sender},
+		Mutex,
+		PoisonError,
+	},
+	thread::{self, JoinHandle},
+	time::Duration,
+};
+
+use astral_thirdparty::slog::{self, Logger};
+
+use super::Error;
+
+/// The global registry, keyed by the type of the kind, the hash of its value and its `Debug`
+/// representation, so colliding hashes are not merged.
+static REGISTRY: Mutex<BTreeMap<(TypeId, u64, String), KindCount>> = Mutex::new(BTreeMap::new());
+
+impl<Kind> Error<Kind> {
+	/// Creates a new error like [`new`] and counts it in the [`telemetry`] registry.
+	///
+	/// [`new`]: #method.new
+	/// [`telemetry`]: telemetry/index.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::error::{telemetry, Error};
+	///
+	/// #[derive(Debug, Hash)]
+	/// enum MyErrorKind {
+	///     Variant,
+	/// }
+	///
+	/// let error = Error::recorded(MyErrorKind::Variant, "oh no!");
+	/// assert_eq!(error.location().line(), line!() - 1);
+	/// assert_eq!(telemetry::snapshot().count(&MyErrorKind::Variant), 1);
+	/// ```
+	#[track_caller]
+	pub fn recorded<E>(kind: Kind, error: E) -> Self
+	where
+		Kind: Hash + Debug + 'static,
+		E: Into<Box<dyn error::Error + Send + Sync>>,
+	{
+		record(&kind);
+		Self::new(kind, error)
+	}
+}
+
+/// Counts an occurrence of `kind` in the registry.
+///
+/// This can be used to record errors, which are created by other constructors than
+/// [`Error::recorded`].
+///
+/// [`Error::recorded`]: ../struct.Error.html#method.recorded
+pub fn record<Kind>(kind: &Kind)
+where
+	Kind: Hash + Debug + 'static,
+{
+	let (type_id, hash) = key(kind);
+	let debug = format!("{kind:?}");
+	let mut registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+	registry
+		.entry((type_id, hash, debug.clone()))
+		.or_insert_with(|| KindCount {
+			key: (type_id, hash),
+			type_name: any::type_name::<Kind>(),
+			kind: debug,
+			count: 0,
+		})
+		.count += 1;
+}
+
+/// Returns the current counters of all recorded kinds.
+#[must_use]
+pub fn snapshot() -> Snapshot {
+	let registry = REGISTRY.lock().unwrap_or_else(PoisonError::into_inner);
+	let mut counts = registry.values().cloned().collect::<Vec<_>>();
+	counts.sort_by(|lhs, rhs| {
+		rhs.count
+			.cmp(&lhs.count)
+			.then_with(|| lhs.type_name.cmp(rhs.type_name))
+			.then_with(|| lhs.kind.cmp(&rhs.kind))
+	});
+	Snapshot { counts }
+}
+
+/// Resets all counters.
+pub fn reset() {
+	REGISTRY
+		.lock()
+		.unwrap_or_else(PoisonError::into_inner)
+		.clear();
+}
+
+/// Logs the total number of recorded errors and the `top` most frequent kinds to `logger`.
+///
+/// # Example
+///
+/// ```
+/// use astral::{
+///     error::{panic::RingDrain, telemetry, Error},
+///     thirdparty::slog,
+/// };
+///
+/// #[derive(Debug, Hash)]
+/// enum MyErrorKind {
+///     NotFound,
+///     InvalidAsset,
+/// }
+///
+/// let _error = Error::recorded(MyErrorKind::NotFound, "cube.obj not found");
+/// let _error = Error::recorded(MyErrorKind::NotFound, "sphere.obj not found");
+/// let _error = Error::recorded(MyErrorKind::InvalidAsset, "corrupt mesh");
+///
+/// let ring = RingDrain::new(8);
+/// let logger = slog::Logger::root(ring.clone(), slog::o!());
+/// telemetry::log_top(&logger, 1);
+///
+/// let records = ring.records();
+/// assert_eq!(records.len(), 2);
+/// assert_eq!(records[0], "INFO errors recorded, kinds: 2, total: 3");
+/// assert!(records[1].starts_with("INFO error kind, count: 2, type: "));
+/// assert!(records[1].ends_with("MyErrorKind, kind: NotFound, rank: 1"));
+/// ```
+pub fn log_top(logger: &Logger, top: usize) {
+	let snapshot = snapshot();
+	slog::info!(logger, "errors recorded"; "total" => snapshot.total(), "kinds" => snapshot.len());
+	for (rank, count) in snapshot.top(top).iter().enumerate() {
+		slog::info!(
+			logger,
+			"error kind";
+			"rank" => rank + 1,
+			"kind" => &count.kind,
+			"type" => count.type_name,
+			"count" => count.count,
+		);
+	}
+}
+
+/// Returns the registry key of `kind`.
+fn key<Kind>(kind: &Kind) -> (TypeId, u64)
+where
+	Kind: Hash + 'static,
+{
+	let mut hasher = DefaultHasher::new();
+	kind.hash(&mut hasher);
+	(TypeId::of::<Kind>(), hasher.finish())
+}
+
+/// The counters of all recorded kinds at a point in time, ordered from the most to the least
+/// frequent kind.
+#[derive(Debug, Clone, Default)]
+pub struct Snapshot {
+	counts: Vec<KindCount>,
+}
+
+impl Snapshot {
+	/// Returns the total number of recorded errors.
+	#[must_use]
+	pub fn total(&self) -> u64 {
+		self.counts.iter().map(KindCount::count).sum()
+	}
+
+	/// Returns how often `kind` was recorded.
+	#[must_use]
+	pub fn count<Kind>(&self, kind: &Kind) -> u64
+	where
+		Kind: Hash + Debug + 'static,
+	{
+		let key = key(kind);
+		let debug = format!("{kind:?}");
+		self.counts
+			.iter()
+			.find(|count| count.key == key && count.kind == debug)
+			.map_or(0, KindCount::count)
+	}
+
+	/// Returns the `n` most frequent kinds.
+	#[must_use]
+	pub fn top(&self, n: usize) -> &[KindCount] {
+		&self.counts[..n.min(self.counts.len())]
+	}
+
+	/// Returns the number of distinct recorded kinds.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.counts.len()
+	}
+
+	/// Returns `true`, if no error was recorded.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.counts.is_empty()
+	}
+
+	/// Returns an iterator over the counters, starting with the most frequent kind.
+	pub fn iter(&self) -> slice::Iter<'_, KindCount> {
+		self.counts.iter()
+	}
+}
+
+impl<'a> IntoIterator for &'a Snapshot {
+	type IntoIter = slice::Iter<'a, KindCount>;
+	type Item = &'a KindCount;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+/// The number of recorded errors of one kind.
+#[derive(Debug, Clone)]
+pub struct KindCount {
+	key: (TypeId, u64),
+	type_name: &'static str,
+	kind: String,
+	count: u64,
+}
+
+impl KindCount {
+	/// Returns the type name of the kind.
+	#[must_use]
+	pub fn type_name(&self) -> &'static str {
+		self.type_name
+	}
+
+	/// Returns the `Debug` representation of the kind.
+	#[must_use]
+	pub fn kind(&self) -> &str {
+		&self.kind
+	}
+
+	/// Returns how often the kind was recorded.
+	#[must_use]
+	pub fn count(&self) -> u64 {
+		self.count
+	}
+}
+
+impl Display for KindCount {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		write!(fmt, "{}: {}", self.kind, self.count)
+	}
+}
+
+/// Periodically logs the most frequent kinds with [`log_top`] on a background thread.
+///
+/// The thread is stopped, when the `Reporter` is dropped.
+///
+/// [`log_top`]: fn.log_top.html
+///
+/// # Example
+///
+/// ```
+/// use std::{
+///     thread,
+///     time::{Duration, Instant},
+/// };
+///
+/// use astral::{
+///     error::{panic::RingDrain, telemetry::Reporter, Error},
+///     thirdparty::slog,
+/// };
+///
+/// #[derive(Debug, Hash)]
+/// enum MyErrorKind {
+///     Variant,
+/// }
+///
+/// let ring = RingDrain::new(8);
+/// let logger = slog::Logger::root(ring.clone(), slog::o!());
+///
+/// let reporter = Reporter::spawn(logger, Duration::from_millis(10), 5).unwrap();
+/// let _error = Error::recorded(MyErrorKind::Variant, "oh no!");
+///
+/// // The thread may be scheduled late, so wait generously for the first report
+/// let deadline = Instant::now() + Duration::from_secs(10);
+/// while !ring.records().iter().any(|record| record.starts_with("INFO errors recorded")) {
+///     assert!(Instant::now() < deadline, "no report was logged");
+///     thread::sleep(Duration::from_millis(10));
+/// }
+///
+/// // Dropping the reporter stops and joins the thread
+/// drop(reporter);
+/// ```
+#[derive(Debug)]
+pub struct Reporter {
+	stop: Option<Sender<()>>,
+	thread: Option<JoinHandle<()>>,
+}
+
+impl Reporter {
+	/// Spawns a thread, which logs the `top` most frequent kinds to `logger` every `interval`.
+	///
+	/// # Errors
+	///
+	/// Returns an error, if the thread could not be spawned.
+	pub fn spawn(logger: Logger, interval: Duration, top: usize) -> io::Result<Self> {
+		let (stop, receiver) = mpsc::channel();
+		let thread = thread::Builder::new()
+			.name("error-telemetry".to_string())
+			.spawn(move || {
+				while let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(interval) {
+					log_top(&logger, top);
+				}
+			})?;
+		Ok(Self {
+			stop: Some(stop),
+			thread: Some(thread),
+		})
+	}
+}
+
+impl Drop for Reporter {
+	fn drop(&mut self) {
+		// Dropping the sender disconnects the channel and wakes up the thread
+		drop(self.stop.take());
+		if let Some(thread) = self.thread.take() {
+			let _ = thread.join();
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	/// A kind, where every value has the same hash.
+	#[derive(Debug)]
+	enum CollidingKind {
+		First,
+		Second,
+	}
+
+	impl Hash for CollidingKind {
+		fn hash<H: Hasher>(&self, _: &mut H) {}
+	}
+
+	#[test]
+	fn test_hash_collision() {
+		record(&CollidingKind::First);
+		record(&CollidingKind::First);
+		record(&CollidingKind::Second);
+
+		let snapshot = snapshot();
+		assert_eq!(snapshot.count(&CollidingKind::First), 2);
+		assert_eq!(snapshot.count(&CollidingKind::Second), 1);
+	}
+}
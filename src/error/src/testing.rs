@@ -0,0 +1,305 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+//! Assertion macros for testing code, which returns [`Error`]s.
+//!
+//! This module is only available with the `testing` feature. On failure, the assertions panic with
+//! the full [`Report`] of the error, including its kind, its causes and their locations.
+//!
+//! [`Error`]: ../struct.Error.html
+//! [`Report`]: ../struct.Report.html
+//!
+//! # Example
+//!
+//! ```
+//! # use std::fmt::{self, Debug, Display, Formatter};
+//! use astral::error::{
+//!     testing::{assert_err_chain, assert_err_kind, assert_err_msg_contains},
+//!     Error,
+//!     ResultExt,
+//! };
+//!
+//! #[derive(Debug, PartialEq)]
+//! enum MyErrorKind {
+//!     NotFound,
+//!     InvalidAsset,
+//! }
+//! # impl Display for MyErrorKind {
+//! #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+//! #         Debug::fmt(self, f)
+//! #     }
+//! # }
+//!
+//! fn load() -> Result<(), Error<MyErrorKind>> {
+//!     Err(Error::new(MyErrorKind::NotFound, "file not found"))
+//!         .chain(MyErrorKind::InvalidAsset, "could not load asset")
+//! }
+//!
+//! assert_err_kind!(load(), MyErrorKind::InvalidAsset);
+//! assert_err_chain!(load(), [MyErrorKind::InvalidAsset, MyErrorKind::NotFound]);
+//! assert_err_msg_contains!(load(), "load asset");
+//! ```
+
+use alloc::{string::ToString, vec::Vec};
+use core::{
+	error,
+	fmt::{self, Arguments, Debug, Display, Formatter},
+};
+
+use super::{report, Error};
+
+pub use crate::{assert_err_chain, assert_err_kind, assert_err_msg_contains};
+
+/// Asserts that a `Result<T, Error<Kind>>` is an `Err` of the given kind.
+///
+/// Like [`assert_eq!`], a custom panic message can be passed after the kind.
+///
+/// # Example
+///
+/// ```should_panic
+/// # use std::fmt::{self, Debug, Display, Formatter};
+/// use astral::error::{testing::assert_err_kind, Error};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyErrorKind {
+///     NotFound,
+///     InvalidAsset,
+/// }
+/// # impl Display for MyErrorKind {
+/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// #         Debug::fmt(self, f)
+/// #     }
+/// # }
+///
+/// let result: Result<(), _> = Err(Error::new(MyErrorKind::NotFound, "file not found"));
+/// assert_err_kind!(result, MyErrorKind::NotFound);
+///
+/// // panics with the report of the error
+/// assert_err_kind!(result, MyErrorKind::InvalidAsset, "while loading {}", "cube.obj");
+/// ```
+#[macro_export]
+macro_rules! assert_err_kind {
+	($result:expr, $kind:expr $(,)?) => {
+		$crate::testing::assert_kind(&$result, &$kind, ::core::option::Option::None)
+	};
+	($result:expr, $kind:expr, $($arg:tt)+) => {
+		$crate::testing::assert_kind(
+			&$result,
+			&$kind,
+			::core::option::Option::Some(::core::format_args!($($arg)+)),
+		)
+	};
+}
+
+/// Asserts that a `Result<T, Error<Kind>>` is an `Err` and that the kinds of the error and its
+/// sources of type `Error<Kind>` are equal to the given list, from outermost to innermost.
+///
+/// Sources of other types are skipped. Like [`assert_eq!`], a custom panic message can be passed
+/// after the kinds.
+///
+/// # Example
+///
+/// ```
+/// # use std::fmt::{self, Debug, Display, Formatter};
+/// use std::io;
+///
+/// use astral::error::{testing::assert_err_chain, Error};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyErrorKind {
+///     NotFound,
+///     InvalidAsset,
+/// }
+/// # impl Display for MyErrorKind {
+/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// #         Debug::fmt(self, f)
+/// #     }
+/// # }
+///
+/// let io_error = io::Error::new(io::ErrorKind::NotFound, "file not found");
+/// let source = Error::new(MyErrorKind::NotFound, io_error);
+/// let error = Error::chained(MyErrorKind::InvalidAsset, "could not load asset", source);
+///
+/// let result: Result<(), _> = Err(error);
+/// assert_err_chain!(result, [MyErrorKind::InvalidAsset, MyErrorKind::NotFound]);
+/// ```
+#[macro_export]
+macro_rules! assert_err_chain {
+	($result:expr, [$($kind:expr),* $(,)?] $(,)?) => {
+		$crate::testing::assert_chain(&$result, &[$(&$kind),*], ::core::option::Option::None)
+	};
+	($result:expr, [$($kind:expr),* $(,)?], $($arg:tt)+) => {
+		$crate::testing::assert_chain(
+			&$result,
+			&[$(&$kind),*],
+			::core::option::Option::Some(::core::format_args!($($arg)+)),
+		)
+	};
+}
+
+/// Asserts that a `Result<T, Error<Kind>>` is an `Err` and that the message of the error contains
+/// the given string.
+///
+/// Only the message of the error itself is checked, not the messages of its causes. Like
+/// [`assert_eq!`], a custom panic message can be passed after the string.
+///
+/// # Example
+///
+/// ```
+/// # use std::fmt::{self, Debug, Display, Formatter};
+/// use astral::error::{testing::assert_err_msg_contains, Error};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum MyErrorKind {
+///     NotFound,
+/// }
+/// # impl Display for MyErrorKind {
+/// #     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+/// #         Debug::fmt(self, f)
+/// #     }
+/// # }
+///
+/// let result: Result<(), _> = Err(Error::new(MyErrorKind::NotFound, "file not found"));
+/// assert_err_msg_contains!(result, "not found");
+/// ```
+#[macro_export]
+macro_rules! assert_err_msg_contains {
+	($result:expr, $pattern:expr $(,)?) => {
+		$crate::testing::assert_msg_contains(&$result, &$pattern, ::core::option::Option::None)
+	};
+	($result:expr, $pattern:expr, $($arg:tt)+) => {
+		$crate::testing::assert_msg_contains(
+			&$result,
+			&$pattern,
+			::core::option::Option::Some(::core::format_args!($($arg)+)),
+		)
+	};
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_kind<T, Kind>(
+	result: &Result<T, Error<Kind>>,
+	kind: &Kind,
+	message: Option<Arguments<'_>>,
+) where
+	Kind: PartialEq + Debug + Display + 'static,
+{
+	let error = unwrap_err(result, message);
+	if error.kind() != kind {
+		fail(
+			"error kind mismatch",
+			&format_args!("expected kind: {kind:?}\n   found kind: {:?}", error.kind()),
+			error,
+			message,
+		);
+	}
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_chain<T, Kind>(
+	result: &Result<T, Error<Kind>>,
+	kinds: &[&Kind],
+	message: Option<Arguments<'_>>,
+) where
+	Kind: PartialEq + Debug + Display + 'static,
+{
+	let error = unwrap_err(result, message);
+	let chain = error
+		.causes()
+		.filter_map(<dyn error::Error>::downcast_ref::<Error<Kind>>)
+		.map(Error::kind)
+		.collect::<Vec<_>>();
+	if chain != kinds {
+		fail(
+			"error chain mismatch",
+			&format_args!("expected chain: {kinds:?}\n   found chain: {chain:?}"),
+			error,
+			message,
+		);
+	}
+}
+
+#[doc(hidden)]
+#[track_caller]
+pub fn assert_msg_contains<T, Kind>(
+	result: &Result<T, Error<Kind>>,
+	pattern: &str,
+	message: Option<Arguments<'_>>,
+) where
+	Kind: Debug + Display + 'static,
+{
+	let error = unwrap_err(result, message);
+	if !error.to_string().contains(pattern) {
+		fail(
+			"error message mismatch",
+			&format_args!("expected message containing: {pattern:?}"),
+			error,
+			message,
+		);
+	}
+}
+
+/// Returns the error of `result` or panics, if `result` is `Ok`.
+#[track_caller]
+fn unwrap_err<'a, T, Kind>(
+	result: &'a Result<T, Error<Kind>>,
+	message: Option<Arguments<'_>>,
+) -> &'a Error<Kind> {
+	match result {
+		Ok(_) => match message {
+			Some(message) => panic!("assertion failed: expected `Err`, found `Ok`: {}", message),
+			None => panic!("assertion failed: expected `Err`, found `Ok`"),
+		},
+		Err(error) => error,
+	}
+}
+
+/// Panics with a description of the failed assertion and the full report of `error`.
+#[track_caller]
+fn fail<Kind>(
+	assertion: &str,
+	details: &dyn Display,
+	error: &Error<Kind>,
+	message: Option<Arguments<'_>>,
+) -> !
+where
+	Kind: Debug + Display + 'static,
+{
+	let report = ReportRef(error);
+	match message {
+		Some(message) => panic!(
+			"assertion failed: {}: {}\n{}\n\nerror report:\n{}",
+			assertion, message, details, report
+		),
+		None => panic!(
+			"assertion failed: {}\n{}\n\nerror report:\n{}",
+			assertion, details, report
+		),
+	}
+}
+
+/// Displays the report of a borrowed error with its kind and locations.
+struct ReportRef<'a, Kind>(&'a Error<Kind>);
+
+impl<Kind> Display for ReportRef<'_, Kind>
+where
+	Kind: Debug + Display + 'static,
+{
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		report::fmt_report(self.0, fmt, true, true, false)
+	}
+}
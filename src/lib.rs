@@ -22,6 +22,7 @@
 #[doc(inline)]
 pub use {
 	astral_error as error,
+	astral_locale as locale,
 	astral_math as math,
 	astral_string as string,
 	astral_thirdparty as thirdparty,
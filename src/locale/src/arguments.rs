@@ -0,0 +1,202 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::fmt::{self, Debug, Display, Formatter, Write};
+
+use astral_error::Error;
+
+use super::{ErrorKind, Result};
+
+/// Arguments, which replace the placeholders of a message.
+///
+/// Positional arguments are referred to by `{0}`, `{1}`, … or consecutively by `{}`, named
+/// arguments by `{name}`. Literal braces are written as `{{` and `}}`.
+///
+/// # Example
+///
+/// ```
+/// use astral::locale::Arguments;
+///
+/// let arguments = Arguments::new().arg(&"Tim").arg(&3).named("item", &"apples");
+/// assert_eq!(
+///     arguments.format("{0} has {1} {item}").unwrap(),
+///     "Tim has 3 apples"
+/// );
+/// assert_eq!(arguments.format("{} has {} {{{item}}}").unwrap(), "Tim has 3 {apples}");
+/// ```
+#[derive(Default)]
+pub struct Arguments<'a> {
+	positional: Vec<&'a dyn Display>,
+	named: Vec<(&'a str, &'a dyn Display)>,
+}
+
+impl<'a> Arguments<'a> {
+	/// Creates an empty list of arguments.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Appends a positional argument.
+	#[must_use]
+	pub fn arg(mut self, value: &'a dyn Display) -> Self {
+		self.positional.push(value);
+		self
+	}
+
+	/// Adds a named argument.
+	///
+	/// If the name was used before, the previous value is replaced.
+	#[must_use]
+	pub fn named(mut self, name: &'a str, value: &'a dyn Display) -> Self {
+		if let Some(argument) = self.named.iter_mut().find(|(key, _)| *key == name) {
+			argument.1 = value;
+		} else {
+			self.named.push((name, value));
+		}
+		self
+	}
+
+	fn get(&self, placeholder: &str, next: &mut usize) -> Option<&'a dyn Display> {
+		if placeholder.is_empty() {
+			*next += 1;
+			self.positional.get(*next - 1).copied()
+		} else if let Ok(index) = placeholder.parse::<usize>() {
+			self.positional.get(index).copied()
+		} else {
+			self.named
+				.iter()
+				.find(|(name, _)| *name == placeholder)
+				.map(|(_, value)| *value)
+		}
+	}
+
+	/// Replaces the placeholders in `message` by the arguments.
+	///
+	/// # Errors
+	///
+	/// Returns an error of kind [`ErrorKind::Format`], if a placeholder is not closed, a brace is
+	/// not escaped or an argument is missing.
+	///
+	/// [`ErrorKind::Format`]: enum.ErrorKind.html#variant.Format
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::locale::{Arguments, ErrorKind};
+	///
+	/// let arguments = Arguments::new().arg(&1);
+	/// assert_eq!(arguments.format("{0}").unwrap(), "1");
+	/// assert_eq!(*arguments.format("{1}").unwrap_err().kind(), ErrorKind::Format);
+	/// assert_eq!(*arguments.format("{0").unwrap_err().kind(), ErrorKind::Format);
+	/// ```
+	pub fn format(&self, message: &str) -> Result<String> {
+		let mut output = String::with_capacity(message.len());
+		let mut next = 0;
+		let mut rest = message;
+		while let Some(index) = rest.find(['{', '}']) {
+			output.push_str(&rest[..index]);
+			let brace = rest.as_bytes()[index];
+			rest = &rest[index + 1..];
+			if rest.as_bytes().first() == Some(&brace) {
+				output.push(char::from(brace));
+				rest = &rest[1..];
+				continue;
+			}
+			if brace == b'}' {
+				return Err(Error::new(
+					ErrorKind::Format,
+					format!("unmatched `}}` in message {message:?}"),
+				));
+			}
+
+			let end = rest.find('}').ok_or_else(|| {
+				Error::new(
+					ErrorKind::Format,
+					format!("unclosed placeholder in message {message:?}"),
+				)
+			})?;
+			let placeholder = rest[..end].trim();
+			let value = self.get(placeholder, &mut next).ok_or_else(|| {
+				Error::new(
+					ErrorKind::Format,
+					format!("missing argument {{{placeholder}}} in message {message:?}"),
+				)
+			})?;
+			write!(output, "{value}").expect("writing to a String cannot fail");
+			rest = &rest[end + 1..];
+		}
+		output.push_str(rest);
+		Ok(output)
+	}
+}
+
+impl Debug for Arguments<'_> {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		let positional = self
+			.positional
+			.iter()
+			.map(ToString::to_string)
+			.collect::<Vec<_>>();
+		let named = self
+			.named
+			.iter()
+			.map(|(name, value)| (*name, value.to_string()))
+			.collect::<Vec<_>>();
+		fmt.debug_struct("Arguments")
+			.field("positional", &positional)
+			.field("named", &named)
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_format() {
+		let arguments = Arguments::new()
+			.arg(&"a")
+			.arg(&2)
+			.named("x", &'x')
+			.named("y", &1.5);
+		assert_eq!(arguments.format("").unwrap(), "");
+		assert_eq!(
+			arguments.format("no placeholders").unwrap(),
+			"no placeholders"
+		);
+		assert_eq!(arguments.format("{1}{0}{1}").unwrap(), "2a2");
+		assert_eq!(arguments.format("{}-{}").unwrap(), "a-2");
+		assert_eq!(arguments.format("{x}={ y }").unwrap(), "x=1.5");
+		assert_eq!(arguments.format("{{x}} {{{x}}}").unwrap(), "{x} {x}");
+		assert_eq!(arguments.format("ä{0}ö").unwrap(), "äaö");
+	}
+
+	#[test]
+	fn test_named_override() {
+		let arguments = Arguments::new().named("x", &1).named("x", &2);
+		assert_eq!(arguments.format("{x}").unwrap(), "2");
+	}
+
+	#[test]
+	fn test_errors() {
+		let arguments = Arguments::new().arg(&"a");
+		for message in &["{", "}", "{0", "a}b", "{}{}", "{2}", "{name}"] {
+			let error = arguments.format(message).unwrap_err();
+			assert_eq!(*error.kind(), ErrorKind::Format, "{message}");
+		}
+	}
+}
@@ -0,0 +1,365 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{
+	collections::{hash_map, HashMap},
+	fmt::{self, Debug, Formatter},
+	fs,
+	hash::{BuildHasher, BuildHasherDefault},
+	path::Path,
+};
+
+use astral_error::{Error, ResultExt};
+use astral_string::{Name, Subsystem, Text};
+use astral_util::hash::Murmur3;
+
+use super::{po, ErrorKind, Locale, PluralCategory, Result};
+
+/// A localized message with one or more plural forms.
+///
+/// # Example
+///
+/// ```
+/// # use astral::thirdparty::slog;
+/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+/// use astral::{
+///     locale::{Message, PluralCategory},
+///     string::Text,
+/// };
+///
+/// let message = Message::plural(vec![
+///     Text::new("{0} file", &string_subsystem),
+///     Text::new("{0} files", &string_subsystem),
+/// ]);
+/// assert_eq!(message.singular(), "{0} file");
+/// assert_eq!(message.form(PluralCategory::Other), "{0} files");
+/// ```
+pub struct Message<'system, H = BuildHasherDefault<Murmur3>> {
+	forms: Vec<Text<'system, H>>,
+}
+
+impl<'system, H> Message<'system, H> {
+	/// Creates a message without plural forms.
+	#[must_use]
+	pub fn new(text: Text<'system, H>) -> Self {
+		Self { forms: vec![text] }
+	}
+
+	/// Creates a message from its plural forms in the order of [`PluralCategory`].
+	///
+	/// [`PluralCategory`]: enum.PluralCategory.html
+	///
+	/// # Panics
+	///
+	/// Panics if `forms` is empty.
+	#[must_use]
+	pub fn plural(forms: Vec<Text<'system, H>>) -> Self {
+		assert!(!forms.is_empty(), "A message requires at least one form");
+		Self { forms }
+	}
+
+	/// Returns the singular form of this message.
+	#[must_use]
+	pub fn singular(&self) -> Text<'system, H> {
+		self.forms[0]
+	}
+
+	/// Returns the form for the given plural category.
+	///
+	/// If the message does not have enough forms, the last form is returned.
+	#[must_use]
+	pub fn form(&self, category: PluralCategory) -> Text<'system, H> {
+		let index = category.index().min(self.forms.len() - 1);
+		self.forms[index]
+	}
+
+	/// Returns all forms of this message.
+	#[must_use]
+	pub fn forms(&self) -> &[Text<'system, H>] {
+		&self.forms
+	}
+}
+
+impl<H> Debug for Message<'_, H> {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.debug_tuple("Message").field(&self.forms).finish()
+	}
+}
+
+/// A string table, which maps [`Name`] keys to localized [`Message`]s of one [`Locale`].
+///
+/// [`Name`]: ../string/struct.Name.html
+/// [`Message`]: struct.Message.html
+/// [`Locale`]: struct.Locale.html
+///
+/// # Example
+///
+/// ```
+/// # use astral::thirdparty::slog;
+/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+/// use astral::{
+///     locale::{Catalog, Locale, Message},
+///     string::{Name, Text},
+/// };
+///
+/// let mut catalog = Catalog::new(Locale::new("fr").unwrap());
+/// let key = Name::new("greeting", &string_subsystem);
+/// catalog.insert(key, Message::new(Text::new("Bonjour !", &string_subsystem)));
+///
+/// assert_eq!(catalog.get(key).unwrap().singular(), "Bonjour !");
+/// ```
+pub struct Catalog<'system, H = BuildHasherDefault<Murmur3>> {
+	locale: Locale,
+	messages: HashMap<Name<'system, H>, Message<'system, H>>,
+}
+
+impl<'system, H> Catalog<'system, H> {
+	/// Creates an empty catalog for the given locale.
+	#[must_use]
+	pub fn new(locale: Locale) -> Self {
+		Self {
+			locale,
+			messages: HashMap::new(),
+		}
+	}
+
+	/// Returns the locale of this catalog.
+	#[must_use]
+	pub fn locale(&self) -> &Locale {
+		&self.locale
+	}
+
+	/// Returns the number of messages in this catalog.
+	#[must_use]
+	pub fn len(&self) -> usize {
+		self.messages.len()
+	}
+
+	/// Returns `true` if this catalog does not contain any messages.
+	#[must_use]
+	pub fn is_empty(&self) -> bool {
+		self.messages.is_empty()
+	}
+
+	/// Inserts a message and returns the previous message with the same key.
+	pub fn insert(
+		&mut self,
+		key: Name<'system, H>,
+		message: Message<'system, H>,
+	) -> Option<Message<'system, H>> {
+		self.messages.insert(key, message)
+	}
+
+	/// Returns the message of the given key.
+	#[must_use]
+	pub fn get(&self, key: Name<'system, H>) -> Option<&Message<'system, H>> {
+		self.messages.get(&key)
+	}
+
+	/// Moves all messages of `other` into this catalog, replacing messages with the same key.
+	pub fn extend(&mut self, other: Self) {
+		self.messages.extend(other.messages);
+	}
+
+	/// Returns an iterator over all keys and messages.
+	#[must_use]
+	pub fn iter(&self) -> hash_map::Iter<'_, Name<'system, H>, Message<'system, H>> {
+		self.messages.iter()
+	}
+}
+
+impl<'system, H> Catalog<'system, H>
+where
+	H: BuildHasher,
+{
+	/// Parses a catalog from the contents of a gettext `.po` file.
+	///
+	/// The keys are the `msgid`s. If an entry has a `msgctxt`, it is prepended to the `msgid`,
+	/// separated by `\u{4}` as in gettext. The header, untranslated and fuzzy entries are skipped,
+	/// so the fallback chain is used for them. Plural forms are expected in the order of
+	/// [`PluralCategory`], the `Plural-Forms` header is ignored.
+	///
+	/// [`PluralCategory`]: enum.PluralCategory.html
+	///
+	/// # Errors
+	///
+	/// Returns an error of kind [`ErrorKind::Parse`], if the source is not a valid `.po` file.
+	///
+	/// [`ErrorKind::Parse`]: enum.ErrorKind.html#variant.Parse
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use astral::thirdparty::slog;
+	/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+	/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+	/// use astral::{
+	///     locale::{Catalog, Locale},
+	///     string::Name,
+	/// };
+	///
+	/// let source = "msgid \"quit\"\nmsgstr \"Beenden\"\n\nmsgid \"open\"\nmsgstr \"\"\n";
+	/// let catalog = Catalog::from_po(Locale::new("de").unwrap(), source, &string_subsystem).unwrap();
+	///
+	/// let quit = Name::new("quit", &string_subsystem);
+	/// assert_eq!(catalog.get(quit).unwrap().singular(), "Beenden");
+	/// assert_eq!(catalog.len(), 1);
+	/// ```
+	pub fn from_po(locale: Locale, source: &str, subsystem: &'system Subsystem<H>) -> Result<Self> {
+		let mut catalog = Self::new(locale);
+		for entry in po::parse(source)? {
+			if entry.id.is_empty() || entry.fuzzy || entry.strings.iter().all(String::is_empty) {
+				continue;
+			}
+			let key = Name::new(entry.key(), subsystem);
+			let forms = entry
+				.strings
+				.iter()
+				.map(|string| Text::new(string, subsystem))
+				.collect();
+			let _ = catalog.insert(key, Message::plural(forms));
+		}
+		Ok(catalog)
+	}
+
+	/// Reads a catalog from a gettext `.po` file.
+	///
+	/// See [`from_po`] for details.
+	///
+	/// [`from_po`]: #method.from_po
+	///
+	/// # Errors
+	///
+	/// Returns an error of kind [`ErrorKind::Io`], if the file could not be read, or of kind
+	/// [`ErrorKind::Parse`], if it is not a valid `.po` file.
+	///
+	/// [`ErrorKind::Io`]: enum.ErrorKind.html#variant.Io
+	/// [`ErrorKind::Parse`]: enum.ErrorKind.html#variant.Parse
+	pub fn load_po<P>(locale: Locale, path: P, subsystem: &'system Subsystem<H>) -> Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		let path = path.as_ref();
+		let source = fs::read_to_string(path).chain_with(ErrorKind::Io, || {
+			format!("could not read catalog {}", path.display())
+		})?;
+		Self::from_po(locale, &source, subsystem).map_err(|error| {
+			Error::chained(
+				ErrorKind::Parse,
+				format!("could not parse catalog {}", path.display()),
+				error,
+			)
+		})
+	}
+}
+
+impl<'catalog, 'system, H> IntoIterator for &'catalog Catalog<'system, H> {
+	type IntoIter = hash_map::Iter<'catalog, Name<'system, H>, Message<'system, H>>;
+	type Item = (&'catalog Name<'system, H>, &'catalog Message<'system, H>);
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl<H> Debug for Catalog<'_, H> {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.debug_struct("Catalog")
+			.field("locale", &self.locale)
+			.field("messages", &self.messages)
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use std::{env, process};
+
+	use astral::thirdparty::slog;
+
+	use super::*;
+
+	const SOURCE: &str = r#"
+msgid ""
+msgstr "Language: fr\n"
+
+msgid "greeting"
+msgstr "Bonjour"
+
+#, fuzzy
+msgid "fuzzy"
+msgstr "flou"
+
+msgid "untranslated"
+msgstr ""
+
+msgctxt "menu"
+msgid "open"
+msgstr "Ouvrir"
+
+msgid "file"
+msgid_plural "files"
+msgstr[0] "{0} fichier"
+msgstr[1] "{0} fichiers"
+"#;
+
+	#[test]
+	fn test_from_po() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger);
+		let catalog =
+			Catalog::from_po(Locale::new("fr").unwrap(), SOURCE, &string_subsystem).unwrap();
+
+		assert_eq!(catalog.len(), 3);
+		let get = |key| catalog.get(Name::new(key, &string_subsystem));
+		assert_eq!(get("greeting").unwrap().singular(), "Bonjour");
+		assert!(get("fuzzy").is_none());
+		assert!(get("untranslated").is_none());
+		assert!(get("").is_none());
+		assert!(get("open").is_none());
+		assert_eq!(get("menu\u{4}open").unwrap().singular(), "Ouvrir");
+
+		let file = get("file").unwrap();
+		assert_eq!(file.forms().len(), 2);
+		assert_eq!(file.form(PluralCategory::One), "{0} fichier");
+		assert_eq!(file.form(PluralCategory::Other), "{0} fichiers");
+		assert_eq!(
+			get("greeting").unwrap().form(PluralCategory::Other),
+			"Bonjour"
+		);
+	}
+
+	#[test]
+	fn test_load_po() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger);
+		let path = env::temp_dir().join(format!("astral-locale-{}.po", process::id()));
+		let locale = Locale::new("fr").unwrap();
+
+		let error = Catalog::load_po(locale.clone(), &path, &string_subsystem).unwrap_err();
+		assert_eq!(*error.kind(), ErrorKind::Io);
+
+		fs::write(&path, SOURCE).unwrap();
+		let catalog = Catalog::load_po(locale.clone(), &path, &string_subsystem).unwrap();
+		assert_eq!(catalog.len(), 3);
+
+		fs::write(&path, "msgid \"a\"").unwrap();
+		let error = Catalog::load_po(locale, &path, &string_subsystem).unwrap_err();
+		assert_eq!(*error.kind(), ErrorKind::Parse);
+		fs::remove_file(path).unwrap();
+	}
+}
@@ -0,0 +1,58 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::fmt::{self, Display, Formatter};
+
+/// The kind of an error, which can occur while loading or using localized strings.
+///
+/// # Example
+///
+/// ```
+/// use astral::locale::{ErrorKind, Locale};
+///
+/// let error = "not a locale!".parse::<Locale>().unwrap_err();
+/// assert_eq!(*error.kind(), ErrorKind::InvalidLocale);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+	/// A locale identifier is malformed.
+	InvalidLocale,
+	/// A catalog file could not be read.
+	Io,
+	/// A catalog file contains invalid syntax.
+	Parse,
+	/// A message could not be found in any locale of the fallback chain.
+	NotFound,
+	/// A message contains an invalid placeholder or refers to a missing argument.
+	Format,
+}
+
+impl Display for ErrorKind {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		let description = match self {
+			ErrorKind::InvalidLocale => "invalid locale",
+			ErrorKind::Io => "could not read catalog",
+			ErrorKind::Parse => "could not parse catalog",
+			ErrorKind::NotFound => "message not found",
+			ErrorKind::Format => "could not format message",
+		};
+		Display::fmt(description, fmt)
+	}
+}
+
+/// A specialized [`Result`] type for localization operations.
+///
+/// [`Result`]: std::result::Result
+pub type Result<T> = astral_error::Result<T, ErrorKind>;
@@ -0,0 +1,132 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+//! Localized string tables.
+//!
+//! A [`Catalog`] maps [`Name`] keys to localized [`Text`]s of one [`Locale`]. Catalogs are loaded
+//! from gettext `.po` files and collected in a [`Localization`], which resolves a key by walking
+//! the fallback chain of the requested locale, e.g. `de-AT` → `de` → `en`.
+//!
+//! Messages may contain placeholders, which are replaced by [`Arguments`], and plural forms, which
+//! are selected by the plural rules of the locale.
+//!
+//! # Example
+//!
+//! ```
+//! # use astral::thirdparty::slog;
+//! # let logger = slog::Logger::root(slog::Discard, slog::o!());
+//! # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+//! use astral::{
+//!     locale::{Arguments, Catalog, Locale, Localization},
+//!     string::Name,
+//! };
+//!
+//! let de: Locale = "de".parse().unwrap();
+//! let catalog = Catalog::from_po(
+//!     de.clone(),
+//!     r#"
+//! msgid "greeting"
+//! msgstr "Hallo {name}!"
+//!
+//! msgid "apples"
+//! msgid_plural "apples"
+//! msgstr[0] "{0} Apfel"
+//! msgstr[1] "{0} Äpfel"
+//! "#,
+//!     &string_subsystem,
+//! )
+//! .unwrap();
+//!
+//! let mut localization = Localization::new("en".parse().unwrap());
+//! localization.add_catalog(catalog);
+//!
+//! let de_at = "de-AT".parse().unwrap();
+//! let greeting = Name::new("greeting", &string_subsystem);
+//! let arguments = Arguments::new().named("name", &"Welt");
+//! assert_eq!(localization.format(&de_at, greeting, &arguments).unwrap(), "Hallo Welt!");
+//!
+//! let apples = Name::new("apples", &string_subsystem);
+//! let arguments = Arguments::new().arg(&3);
+//! assert_eq!(localization.format_plural(&de, apples, 3, &arguments).unwrap(), "3 Äpfel");
+//! ```
+//!
+//! [`Catalog`]: struct.Catalog.html
+//! [`Locale`]: struct.Locale.html
+//! [`Localization`]: struct.Localization.html
+//! [`Arguments`]: struct.Arguments.html
+//! [`Name`]: ../string/struct.Name.html
+//! [`Text`]: ../string/struct.Text.html
+
+#![doc(
+	html_no_source,
+	html_logo_url = "https://astral-engine.github.io/docs/logo_astral.svg",
+	html_favicon_url = "https://astral-engine.github.io/docs/logo.svg",
+	test(attr(
+		deny(
+			future_incompatible,
+			nonstandard_style,
+			rust_2018_compatibility,
+			rust_2018_idioms,
+			unused,
+			macro_use_extern_crate,
+			trivial_casts,
+			trivial_numeric_casts,
+			unused_import_braces,
+			unused_lifetimes,
+			unused_qualifications,
+			variant_size_differences,
+		),
+		allow(unused_extern_crates)
+	))
+)]
+#![warn(
+	future_incompatible,
+	nonstandard_style,
+	rust_2018_compatibility,
+	rust_2018_idioms,
+	unused,
+	macro_use_extern_crate,
+	missing_copy_implementations,
+	missing_debug_implementations,
+	missing_docs,
+	// missing_doc_code_examples,
+	// single_use_lifetimes,
+	trivial_casts,
+	trivial_numeric_casts,
+	unreachable_pub,
+	unused_import_braces,
+	unused_lifetimes,
+	unused_qualifications,
+	unused_results,
+	variant_size_differences,
+	clippy::pedantic
+)]
+
+mod arguments;
+mod catalog;
+mod error;
+mod locale;
+mod localization;
+mod plural;
+mod po;
+
+pub use self::{
+	arguments::Arguments,
+	catalog::{Catalog, Message},
+	error::{ErrorKind, Result},
+	locale::Locale,
+	localization::Localization,
+	plural::PluralCategory,
+};
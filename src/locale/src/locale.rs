@@ -0,0 +1,234 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{
+	fmt::{self, Display, Formatter},
+	str::FromStr,
+};
+
+use astral_error::Error;
+
+use super::{ErrorKind, PluralCategory, Result};
+
+/// A language identifier like `en`, `de-AT` or `zh-Hant-TW`.
+///
+/// A `Locale` consists of a language subtag followed by optional subtags, which are separated by
+/// `-` or `_`. The subtags are normalized when parsing: the language is stored in lower case, a
+/// script in title case and a region in upper case.
+///
+/// # Example
+///
+/// ```
+/// use astral::locale::Locale;
+///
+/// let locale: Locale = "de_at".parse().unwrap();
+/// assert_eq!(locale.to_string(), "de-AT");
+/// assert_eq!(locale.language(), "de");
+/// assert_eq!(locale.region(), Some("AT"));
+/// assert_eq!(locale.parent(), Some("de".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Locale {
+	tag: String,
+}
+
+impl Locale {
+	/// Parses a locale identifier.
+	///
+	/// # Errors
+	///
+	/// Returns an error of kind [`ErrorKind::InvalidLocale`], if the language is not two, three or
+	/// five to eight letters long or another subtag is not one to eight alphanumeric characters
+	/// long.
+	///
+	/// [`ErrorKind::InvalidLocale`]: enum.ErrorKind.html#variant.InvalidLocale
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::locale::Locale;
+	///
+	/// assert!(Locale::new("fr-CA").is_ok());
+	/// assert!(Locale::new("fr CA").is_err());
+	/// ```
+	pub fn new(identifier: &str) -> Result<Self> {
+		let mut tag = String::with_capacity(identifier.len());
+		for (index, subtag) in identifier.split(['-', '_']).enumerate() {
+			let is_valid = if index == 0 {
+				(2..=8).contains(&subtag.len())
+					&& subtag.len() != 4
+					&& subtag.bytes().all(|b| b.is_ascii_alphabetic())
+			} else {
+				(1..=8).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+			};
+			if !is_valid {
+				return Err(Error::new(
+					ErrorKind::InvalidLocale,
+					format!("invalid subtag {subtag:?} in locale {identifier:?}"),
+				));
+			}
+
+			if index == 0 {
+				tag.push_str(&subtag.to_ascii_lowercase());
+				continue;
+			}
+			tag.push('-');
+			if subtag.len() == 4 && subtag.bytes().all(|b| b.is_ascii_alphabetic()) {
+				tag.push_str(&subtag[..1].to_ascii_uppercase());
+				tag.push_str(&subtag[1..].to_ascii_lowercase());
+			} else if Self::is_region(subtag) {
+				tag.push_str(&subtag.to_ascii_uppercase());
+			} else {
+				tag.push_str(&subtag.to_ascii_lowercase());
+			}
+		}
+		Ok(Self { tag })
+	}
+
+	fn is_region(subtag: &str) -> bool {
+		(subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+			|| (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+	}
+
+	/// Returns the normalized identifier of this locale.
+	#[must_use]
+	pub fn as_str(&self) -> &str {
+		&self.tag
+	}
+
+	/// Returns the language subtag.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::locale::Locale;
+	///
+	/// assert_eq!(Locale::new("zh-Hant-TW").unwrap().language(), "zh");
+	/// ```
+	#[must_use]
+	pub fn language(&self) -> &str {
+		self.tag.split('-').next().unwrap_or(&self.tag)
+	}
+
+	/// Returns the region subtag, if any.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::locale::Locale;
+	///
+	/// assert_eq!(Locale::new("zh-Hant-TW").unwrap().region(), Some("TW"));
+	/// assert_eq!(Locale::new("es-419").unwrap().region(), Some("419"));
+	/// assert_eq!(Locale::new("en").unwrap().region(), None);
+	/// ```
+	#[must_use]
+	pub fn region(&self) -> Option<&str> {
+		self.tag
+			.split('-')
+			.skip(1)
+			.find(|subtag| Self::is_region(subtag))
+	}
+
+	/// Returns the locale without its last subtag, or `None` if this only consists of a language.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::locale::Locale;
+	///
+	/// let locale = Locale::new("zh-Hant-TW").unwrap();
+	/// let parent = locale.parent().unwrap();
+	/// assert_eq!(parent.as_str(), "zh-Hant");
+	/// assert_eq!(parent.parent().unwrap().as_str(), "zh");
+	/// assert_eq!(parent.parent().unwrap().parent(), None);
+	/// ```
+	#[must_use]
+	pub fn parent(&self) -> Option<Self> {
+		self.tag.rfind('-').map(|index| Self {
+			tag: self.tag[..index].to_string(),
+		})
+	}
+
+	/// Returns the plural category of `n` in this locale.
+	///
+	/// Plural rules are implemented for English, German and French. Other languages use the
+	/// English rule.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::locale::{Locale, PluralCategory};
+	///
+	/// let de = Locale::new("de").unwrap();
+	/// assert_eq!(de.plural_category(1), PluralCategory::One);
+	/// assert_eq!(de.plural_category(2), PluralCategory::Other);
+	/// ```
+	#[must_use]
+	pub fn plural_category(&self, n: u64) -> PluralCategory {
+		PluralCategory::of(self.language(), n)
+	}
+}
+
+impl FromStr for Locale {
+	type Err = Error<ErrorKind>;
+
+	fn from_str(identifier: &str) -> Result<Self> {
+		Self::new(identifier)
+	}
+}
+
+impl Display for Locale {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.tag, fmt)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		assert_eq!(Locale::new("EN").unwrap().as_str(), "en");
+		assert_eq!(Locale::new("en_us").unwrap().as_str(), "en-US");
+		assert_eq!(Locale::new("sr-latn-rs").unwrap().as_str(), "sr-Latn-RS");
+		assert_eq!(Locale::new("de-CH-1996").unwrap().as_str(), "de-CH-1996");
+		assert_eq!(Locale::new("es-419").unwrap().region(), Some("419"));
+		assert_eq!(Locale::new("de-1996").unwrap().region(), None);
+
+		for invalid in &[
+			"",
+			"e",
+			"engl",
+			"en-",
+			"-en",
+			"en--US",
+			"de-AT-toolongsubtag",
+			"d3",
+		] {
+			let error = Locale::new(invalid).unwrap_err();
+			assert_eq!(*error.kind(), ErrorKind::InvalidLocale, "{invalid}");
+		}
+	}
+
+	#[test]
+	fn test_parent() {
+		let locale = Locale::new("de-CH-1996").unwrap();
+		let parents = std::iter::successors(Some(locale), Locale::parent)
+			.map(|locale| locale.to_string())
+			.collect::<Vec<_>>();
+		assert_eq!(parents, ["de-CH-1996", "de-CH", "de"]);
+	}
+}
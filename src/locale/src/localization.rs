@@ -0,0 +1,351 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{
+	collections::HashMap,
+	fmt::{self, Debug, Formatter},
+	hash::BuildHasherDefault,
+	iter,
+};
+
+use astral_error::Error;
+use astral_string::{Name, Text};
+use astral_util::hash::Murmur3;
+
+use super::{Arguments, Catalog, ErrorKind, Locale, Message, Result};
+
+/// A collection of [`Catalog`]s, which resolves messages along a fallback chain.
+///
+/// When a message is requested for a [`Locale`], the catalogs of the locale and its parents are
+/// searched first, then the fallback locale and its parents. For example, with the fallback
+/// locale `en`, `de-AT` resolves to `de-AT` → `de` → `en`.
+///
+/// [`Catalog`]: struct.Catalog.html
+/// [`Locale`]: struct.Locale.html
+///
+/// # Example
+///
+/// ```
+/// # use astral::thirdparty::slog;
+/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+/// use astral::{
+///     locale::{Catalog, Locale, Localization, Message},
+///     string::{Name, Text},
+/// };
+///
+/// let en = Locale::new("en").unwrap();
+/// let key = Name::new("color", &string_subsystem);
+/// let mut catalog = Catalog::new(en.clone());
+/// catalog.insert(key, Message::new(Text::new("color", &string_subsystem)));
+///
+/// let mut localization = Localization::new(en);
+/// localization.add_catalog(catalog);
+///
+/// let en_gb = Locale::new("en-GB").unwrap();
+/// assert_eq!(localization.get(&en_gb, key).unwrap(), "color");
+///
+/// let mut catalog = Catalog::new(en_gb.clone());
+/// catalog.insert(key, Message::new(Text::new("colour", &string_subsystem)));
+/// localization.add_catalog(catalog);
+/// assert_eq!(localization.get(&en_gb, key).unwrap(), "colour");
+/// ```
+pub struct Localization<'system, H = BuildHasherDefault<Murmur3>> {
+	fallback: Locale,
+	catalogs: HashMap<Locale, Catalog<'system, H>>,
+}
+
+impl<'system, H> Localization<'system, H> {
+	/// Creates an empty localization with the given fallback locale.
+	#[must_use]
+	pub fn new(fallback: Locale) -> Self {
+		Self {
+			fallback,
+			catalogs: HashMap::new(),
+		}
+	}
+
+	/// Returns the fallback locale.
+	#[must_use]
+	pub fn fallback(&self) -> &Locale {
+		&self.fallback
+	}
+
+	/// Adds a catalog.
+	///
+	/// If a catalog for the same locale was added before, the messages are merged and messages of
+	/// the new catalog take precedence.
+	pub fn add_catalog(&mut self, catalog: Catalog<'system, H>) {
+		if let Some(existing) = self.catalogs.get_mut(catalog.locale()) {
+			existing.extend(catalog);
+		} else {
+			let _ = self.catalogs.insert(catalog.locale().clone(), catalog);
+		}
+	}
+
+	/// Returns the catalog of exactly the given locale.
+	#[must_use]
+	pub fn catalog(&self, locale: &Locale) -> Option<&Catalog<'system, H>> {
+		self.catalogs.get(locale)
+	}
+
+	/// Returns the locales, which are searched for a message requested in `locale`.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::locale::{Locale, Localization};
+	///
+	/// let localization: Localization<'_> = Localization::new(Locale::new("en").unwrap());
+	/// let chain = localization.fallback_chain(&Locale::new("de-AT").unwrap());
+	/// assert_eq!(chain, ["de-AT".parse().unwrap(), "de".parse().unwrap(), "en".parse().unwrap()]);
+	/// ```
+	#[must_use]
+	pub fn fallback_chain(&self, locale: &Locale) -> Vec<Locale> {
+		let mut chain: Vec<Locale> = Vec::new();
+		let locales = iter::successors(Some(locale.clone()), Locale::parent).chain(
+			iter::successors(Some(self.fallback.clone()), Locale::parent),
+		);
+		for locale in locales {
+			if !chain.contains(&locale) {
+				chain.push(locale);
+			}
+		}
+		chain
+	}
+
+	/// Returns the message of `key` and the locale of the catalog it was found in.
+	#[must_use]
+	pub fn message(
+		&self,
+		locale: &Locale,
+		key: Name<'system, H>,
+	) -> Option<(&Locale, &Message<'system, H>)> {
+		self.fallback_chain(locale).iter().find_map(|locale| {
+			let catalog = self.catalogs.get(locale)?;
+			catalog.get(key).map(|message| (catalog.locale(), message))
+		})
+	}
+
+	/// Returns the singular form of the message of `key`.
+	#[must_use]
+	pub fn get(&self, locale: &Locale, key: Name<'system, H>) -> Option<Text<'system, H>> {
+		self.message(locale, key)
+			.map(|(_, message)| message.singular())
+	}
+
+	/// Returns the plural form of the message of `key` for `n`.
+	///
+	/// The form is selected by the plural rules of the locale, in which the message was found.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use astral::thirdparty::slog;
+	/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+	/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+	/// use astral::{
+	///     locale::{Catalog, Locale, Localization},
+	///     string::Name,
+	/// };
+	///
+	/// let source = "msgid \"life\"\nmsgid_plural \"lives\"\nmsgstr[0] \"vie\"\nmsgstr[1] \"vies\"";
+	/// let fr = Locale::new("fr").unwrap();
+	/// let mut localization = Localization::new(Locale::new("en").unwrap());
+	/// localization.add_catalog(Catalog::from_po(fr.clone(), source, &string_subsystem).unwrap());
+	///
+	/// let lives = Name::new("life", &string_subsystem);
+	/// assert_eq!(localization.get_plural(&fr, lives, 0).unwrap(), "vie");
+	/// assert_eq!(localization.get_plural(&fr, lives, 2).unwrap(), "vies");
+	/// ```
+	#[must_use]
+	pub fn get_plural(
+		&self,
+		locale: &Locale,
+		key: Name<'system, H>,
+		n: u64,
+	) -> Option<Text<'system, H>> {
+		self.message(locale, key)
+			.map(|(locale, message)| message.form(locale.plural_category(n)))
+	}
+
+	/// Formats the singular form of the message of `key` with the given arguments.
+	///
+	/// # Errors
+	///
+	/// Returns an error of kind [`ErrorKind::NotFound`], if the message does not exist in the
+	/// fallback chain, or of kind [`ErrorKind::Format`], if the arguments do not match the message.
+	///
+	/// [`ErrorKind::NotFound`]: enum.ErrorKind.html#variant.NotFound
+	/// [`ErrorKind::Format`]: enum.ErrorKind.html#variant.Format
+	pub fn format(
+		&self,
+		locale: &Locale,
+		key: Name<'system, H>,
+		arguments: &Arguments<'_>,
+	) -> Result<String> {
+		let text = self
+			.get(locale, key)
+			.ok_or_else(|| not_found(locale, key))?;
+		arguments.format(&text)
+	}
+
+	/// Formats the plural form of the message of `key` for `n` with the given arguments.
+	///
+	/// `n` is only used to select the plural form, it has to be passed as argument to be
+	/// displayed.
+	///
+	/// # Errors
+	///
+	/// Returns an error of kind [`ErrorKind::NotFound`], if the message does not exist in the
+	/// fallback chain, or of kind [`ErrorKind::Format`], if the arguments do not match the message.
+	///
+	/// [`ErrorKind::NotFound`]: enum.ErrorKind.html#variant.NotFound
+	/// [`ErrorKind::Format`]: enum.ErrorKind.html#variant.Format
+	pub fn format_plural(
+		&self,
+		locale: &Locale,
+		key: Name<'system, H>,
+		n: u64,
+		arguments: &Arguments<'_>,
+	) -> Result<String> {
+		let text = self
+			.get_plural(locale, key, n)
+			.ok_or_else(|| not_found(locale, key))?;
+		arguments.format(&text)
+	}
+}
+
+fn not_found<H>(locale: &Locale, key: Name<'_, H>) -> Error<ErrorKind> {
+	Error::new(
+		ErrorKind::NotFound,
+		format!("message {key:?} not found for locale {locale}"),
+	)
+}
+
+impl<H> Debug for Localization<'_, H> {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.debug_struct("Localization")
+			.field("fallback", &self.fallback)
+			.field("catalogs", &self.catalogs)
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use astral::thirdparty::slog;
+	use astral_string::Subsystem;
+
+	use super::*;
+
+	#[test]
+	fn test_fallback() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger);
+		let mut localization = Localization::new(Locale::new("en").unwrap());
+		let catalogs = [
+			(
+				"en",
+				"msgid \"a\"\nmsgstr \"a-en\"\nmsgid \"b\"\nmsgstr \"b-en\"\nmsgid \"c\"\nmsgstr \"c-en\"",
+			),
+			(
+				"de",
+				"msgid \"a\"\nmsgstr \"a-de\"\nmsgid \"b\"\nmsgstr \"b-de\"",
+			),
+			("de-AT", "msgid \"a\"\nmsgstr \"a-de-AT\""),
+		];
+		for (locale, source) in &catalogs {
+			let catalog =
+				Catalog::from_po(Locale::new(locale).unwrap(), source, &string_subsystem).unwrap();
+			localization.add_catalog(catalog);
+		}
+
+		let get = |locale, key| {
+			localization
+				.get(
+					&Locale::new(locale).unwrap(),
+					Name::new(key, &string_subsystem),
+				)
+				.map(Text::as_str)
+		};
+		assert_eq!(get("de-AT", "a"), Some("a-de-AT"));
+		assert_eq!(get("de-AT", "b"), Some("b-de"));
+		assert_eq!(get("de-AT", "c"), Some("c-en"));
+		assert_eq!(get("de-CH", "a"), Some("a-de"));
+		assert_eq!(get("fr", "a"), Some("a-en"));
+		assert_eq!(get("de", "d"), None);
+
+		let de = Locale::new("de").unwrap();
+		let d = Name::new("d", &string_subsystem);
+		let error = localization.format(&de, d, &Arguments::new()).unwrap_err();
+		assert_eq!(*error.kind(), ErrorKind::NotFound);
+	}
+
+	#[test]
+	fn test_plural_fallback() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger);
+		let mut localization = Localization::new(Locale::new("en").unwrap());
+		let catalogs = [
+			(
+				"en",
+				"msgid \"day\"\nmsgid_plural \"days\"\nmsgstr[0] \"{0} day\"\nmsgstr[1] \"{0} days\"",
+			),
+			("fr", "msgid \"x\"\nmsgstr \"x\""),
+		];
+		for (locale, source) in &catalogs {
+			let catalog =
+				Catalog::from_po(Locale::new(locale).unwrap(), source, &string_subsystem).unwrap();
+			localization.add_catalog(catalog);
+		}
+
+		// The message is only available in English, so the English rule applies for 0
+		let fr = Locale::new("fr").unwrap();
+		let day = Name::new("day", &string_subsystem);
+		let format = |n| localization.format_plural(&fr, day, n, &Arguments::new().arg(&n));
+		assert_eq!(format(0).unwrap(), "0 days");
+		assert_eq!(format(1).unwrap(), "1 day");
+		assert_eq!(format(2).unwrap(), "2 days");
+	}
+
+	#[test]
+	fn test_merge() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger);
+		let en = Locale::new("en").unwrap();
+		let mut localization = Localization::new(en.clone());
+		for source in &[
+			"msgid \"a\"\nmsgstr \"1\"\nmsgid \"b\"\nmsgstr \"2\"",
+			"msgid \"a\"\nmsgstr \"3\"",
+		] {
+			let catalog = Catalog::from_po(en.clone(), source, &string_subsystem).unwrap();
+			localization.add_catalog(catalog);
+		}
+		assert_eq!(localization.catalog(&en).unwrap().len(), 2);
+		assert_eq!(
+			localization
+				.get(&en, Name::new("a", &string_subsystem))
+				.unwrap(),
+			"3"
+		);
+		assert_eq!(
+			localization
+				.get(&en, Name::new("b", &string_subsystem))
+				.unwrap(),
+			"2"
+		);
+	}
+}
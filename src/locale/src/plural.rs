@@ -0,0 +1,82 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+/// The plural category of a number.
+///
+/// Catalogs store plural forms in the order of the categories, so `One` selects the first form
+/// (`msgstr[0]`) and `Other` the second one (`msgstr[1]`).
+///
+/// # Example
+///
+/// ```
+/// use astral::locale::{Locale, PluralCategory};
+///
+/// let en: Locale = "en".parse().unwrap();
+/// let fr: Locale = "fr".parse().unwrap();
+/// assert_eq!(en.plural_category(0), PluralCategory::Other);
+/// assert_eq!(fr.plural_category(0), PluralCategory::One);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+	/// The singular form, e.g. "1 apple".
+	One,
+	/// The plural form, e.g. "2 apples".
+	Other,
+}
+
+impl PluralCategory {
+	/// Returns the category of `n` in the given language.
+	///
+	/// English and German use the singular form only for `1`, French also uses it for `0`.
+	/// Unsupported languages fall back to the English rule, which is also the default of gettext.
+	pub(super) fn of(language: &str, n: u64) -> Self {
+		let one = match language {
+			"fr" => n <= 1,
+			_ => n == 1,
+		};
+		if one {
+			PluralCategory::One
+		} else {
+			PluralCategory::Other
+		}
+	}
+
+	/// Returns the index of the plural form in a catalog.
+	pub(super) fn index(self) -> usize {
+		match self {
+			PluralCategory::One => 0,
+			PluralCategory::Other => 1,
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_rules() {
+		for language in &["en", "de", "xx"] {
+			assert_eq!(PluralCategory::of(language, 0), PluralCategory::Other);
+			assert_eq!(PluralCategory::of(language, 1), PluralCategory::One);
+			assert_eq!(PluralCategory::of(language, 2), PluralCategory::Other);
+			assert_eq!(PluralCategory::of(language, 21), PluralCategory::Other);
+		}
+		assert_eq!(PluralCategory::of("fr", 0), PluralCategory::One);
+		assert_eq!(PluralCategory::of("fr", 1), PluralCategory::One);
+		assert_eq!(PluralCategory::of("fr", 2), PluralCategory::Other);
+		assert_eq!(PluralCategory::of("fr", 1_000_000), PluralCategory::Other);
+	}
+}
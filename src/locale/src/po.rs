@@ -0,0 +1,266 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::mem;
+
+use astral_error::Error;
+
+use super::{ErrorKind, Result};
+
+/// An entry of a gettext `.po` file.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(super) struct Entry {
+	pub(super) context: Option<String>,
+	pub(super) id: String,
+	pub(super) id_plural: Option<String>,
+	pub(super) strings: Vec<String>,
+	pub(super) fuzzy: bool,
+}
+
+impl Entry {
+	/// Returns the key of this entry.
+	///
+	/// Like in gettext, the context is separated from the id by `\u{4}`.
+	pub(super) fn key(&self) -> String {
+		match &self.context {
+			Some(context) => format!("{context}\u{4}{}", self.id),
+			None => self.id.clone(),
+		}
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Field {
+	None,
+	Context,
+	Id,
+	IdPlural,
+	String(usize),
+}
+
+/// A minimal parser for gettext `.po` files.
+///
+/// Supported are `msgctxt`, `msgid`, `msgid_plural`, `msgstr` and `msgstr[n]` with multi-line
+/// strings, and the `fuzzy` flag. Other comments and obsolete entries (`#~`) are skipped.
+pub(super) fn parse(source: &str) -> Result<Vec<Entry>> {
+	let mut entries = Vec::new();
+	let mut entry = Entry::default();
+	let mut field = Field::None;
+
+	for (index, line) in source.lines().enumerate() {
+		let line_number = index + 1;
+		let line = line.trim();
+		if line.is_empty() {
+			continue;
+		}
+		if line.starts_with('#') {
+			if field != Field::None && !matches!(field, Field::String(_)) {
+				return Err(error(line_number, "comment inside of an entry"));
+			}
+			if let Some(flags) = line.strip_prefix("#,") {
+				finish(&mut entries, &mut entry, &mut field, line_number)?;
+				entry.fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+			}
+			continue;
+		}
+		if line.starts_with('"') {
+			let string = unquote(line, line_number)?;
+			match field {
+				Field::None => return Err(error(line_number, "string without keyword")),
+				Field::Context => entry
+					.context
+					.get_or_insert_with(String::new)
+					.push_str(&string),
+				Field::Id => entry.id.push_str(&string),
+				Field::IdPlural => entry
+					.id_plural
+					.get_or_insert_with(String::new)
+					.push_str(&string),
+				Field::String(n) => entry.strings[n].push_str(&string),
+			}
+			continue;
+		}
+
+		let (keyword, rest) = line.split_at(line.find(char::is_whitespace).unwrap_or(line.len()));
+		let string = unquote(rest.trim(), line_number)?;
+		let next = match keyword {
+			"msgctxt" => Field::Context,
+			"msgid" => Field::Id,
+			"msgid_plural" => Field::IdPlural,
+			"msgstr" => Field::String(0),
+			_ => {
+				let n = keyword
+					.strip_prefix("msgstr[")
+					.and_then(|n| n.strip_suffix(']'))
+					.and_then(|n| n.parse().ok())
+					.ok_or_else(|| error(line_number, &format!("unknown keyword `{keyword}`")))?;
+				Field::String(n)
+			}
+		};
+
+		let is_valid = match (field, next) {
+			(Field::None, Field::Context | Field::Id)
+			| (Field::Context, Field::Id)
+			| (Field::Id, Field::IdPlural) => true,
+			(Field::String(_), Field::Context | Field::Id) => {
+				finish(&mut entries, &mut entry, &mut field, line_number)?;
+				true
+			}
+			(Field::Id, Field::String(0)) => entry.id_plural.is_none() && keyword == "msgstr",
+			(Field::IdPlural | Field::String(_), Field::String(n)) => {
+				entry.id_plural.is_some() && keyword != "msgstr" && n == entry.strings.len()
+			}
+			_ => false,
+		};
+		if !is_valid {
+			return Err(error(
+				line_number,
+				&format!("unexpected keyword `{keyword}`"),
+			));
+		}
+
+		match next {
+			Field::Context => entry.context = Some(string),
+			Field::Id => entry.id = string,
+			Field::IdPlural => entry.id_plural = Some(string),
+			Field::String(_) => entry.strings.push(string),
+			Field::None => unreachable!(),
+		}
+		field = next;
+	}
+	finish(&mut entries, &mut entry, &mut field, source.lines().count())?;
+	Ok(entries)
+}
+
+fn finish(
+	entries: &mut Vec<Entry>,
+	entry: &mut Entry,
+	field: &mut Field,
+	line_number: usize,
+) -> Result<()> {
+	match *field {
+		Field::None => Ok(()),
+		Field::String(_) => {
+			entries.push(mem::take(entry));
+			*field = Field::None;
+			Ok(())
+		}
+		_ => Err(error(line_number, "entry without `msgstr`")),
+	}
+}
+
+fn unquote(string: &str, line_number: usize) -> Result<String> {
+	let inner = string
+		.strip_prefix('"')
+		.and_then(|string| string.strip_suffix('"'))
+		.filter(|_| string.len() >= 2)
+		.ok_or_else(|| error(line_number, "expected a quoted string"))?;
+
+	let mut unquoted = String::with_capacity(inner.len());
+	let mut chars = inner.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'"' => return Err(error(line_number, "unescaped quote")),
+			'\\' => unquoted.push(match chars.next() {
+				Some('n') => '\n',
+				Some('t') => '\t',
+				Some('r') => '\r',
+				Some('"') => '"',
+				Some('\\') => '\\',
+				_ => return Err(error(line_number, "invalid escape sequence")),
+			}),
+			c => unquoted.push(c),
+		}
+	}
+	Ok(unquoted)
+}
+
+fn error(line_number: usize, message: &str) -> Error<ErrorKind> {
+	Error::new(ErrorKind::Parse, format!("line {line_number}: {message}"))
+}
+
+#[cfg(test)]
+mod test {
+	use super::*;
+
+	#[test]
+	fn test_parse() {
+		let entries = parse(
+			r#"
+# Translator comment
+msgid ""
+msgstr ""
+"Language: de\n"
+
+#: src/main.rs:42
+msgid "greeting"
+msgstr "Hallo "
+"Welt!"
+msgid "quote"
+msgstr "\"\\\t\n"
+
+#, fuzzy, c-format
+msgctxt "menu"
+msgid "open"
+msgstr "Öffnen"
+
+msgid "apple"
+msgid_plural "apples"
+msgstr[0] "Apfel"
+msgstr[1] "Äpfel"
+
+#~ msgid "obsolete"
+#~ msgstr "veraltet"
+"#,
+		)
+		.unwrap();
+
+		assert_eq!(entries.len(), 5);
+		assert_eq!(entries[0].id, "");
+		assert_eq!(entries[0].strings, ["Language: de\n"]);
+		assert_eq!(entries[1].key(), "greeting");
+		assert_eq!(entries[1].strings, ["Hallo Welt!"]);
+		assert!(!entries[1].fuzzy);
+		assert_eq!(entries[2].strings, ["\"\\\t\n"]);
+		assert_eq!(entries[3].key(), "menu\u{4}open");
+		assert!(entries[3].fuzzy);
+		assert_eq!(entries[4].id_plural.as_deref(), Some("apples"));
+		assert_eq!(entries[4].strings, ["Apfel", "Äpfel"]);
+		assert!(!entries[4].fuzzy);
+	}
+
+	#[test]
+	fn test_errors() {
+		let invalid = [
+			"msgstr \"a\"",
+			"msgid \"a\"",
+			"msgid \"a\"\nmsgstr[0] \"b\"",
+			"msgid \"a\"\nmsgid_plural \"b\"\nmsgstr \"c\"",
+			"msgid \"a\"\nmsgid_plural \"b\"\nmsgstr[1] \"c\"",
+			"msgid \"a\"\nmsgstr \"b\"\nmsgstr \"c\"",
+			"msgid \"a\"\n# comment\nmsgstr \"b\"",
+			"msgid a\nmsgstr \"b\"",
+			"msgid \"a\\x\"\nmsgstr \"b\"",
+			"msgid \"a\"b\"\nmsgstr \"b\"",
+			"msgid \"a\"\nmsgstr \"b\"\nmsgfoo \"c\"",
+			"\"a\"",
+		];
+		for source in &invalid {
+			let error = parse(source).unwrap_err();
+			assert_eq!(*error.kind(), ErrorKind::Parse, "{source}");
+		}
+		assert_eq!(parse("msgid \"a\"\nmsgstr \"b\"").unwrap().len(), 1);
+	}
+}
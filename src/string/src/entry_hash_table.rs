@@ -28,7 +28,7 @@ use astral_thirdparty::slog::{warn, Logger};
 
 use super::{Allocator, Entry, StaticRefVector, StringId, MAX_STRING_LENGTH};
 
-const NUM_BUCKETS: usize = u16::max_value() as usize + 1;
+pub(super) const NUM_BUCKETS: usize = u16::max_value() as usize + 1;
 
 pub(super) struct EntryHashTable {
 	head: Box<[AtomicPtr<Entry>; NUM_BUCKETS]>,
@@ -142,3 +142,45 @@ impl Display for Utf16Error {
 }
 
 impl error::Error for Utf16Error {}
+
+/// An error which can be returned when compiling a [`NamePattern`].
+///
+/// [`NamePattern`]: struct.NamePattern.html
+///
+/// # Example
+///
+/// ```
+/// use astral::string::NamePattern;
+///
+/// let error = NamePattern::new("door_[a-z").unwrap_err();
+/// assert_eq!(error.position(), 5);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PatternError {
+	position: usize,
+	description: &'static str,
+}
+
+impl PatternError {
+	pub(super) fn new(position: usize, description: &'static str) -> Self {
+		Self {
+			position,
+			description,
+		}
+	}
+
+	/// Returns the byte index in the pattern, where the invalid token starts.
+	#[inline]
+	#[must_use]
+	pub fn position(&self) -> usize {
+		self.position
+	}
+}
+
+impl Display for PatternError {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		write!(fmt, "{} at position {}", self.description, self.position)
+	}
+}
+
+impl error::Error for PatternError {}
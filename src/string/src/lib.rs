@@ -151,7 +151,10 @@ mod allocator;
 mod entry;
 mod entry_hash_table;
 mod error;
+mod mapped_table;
 mod name;
+mod normalization;
+mod pattern;
 mod static_ref_vector;
 mod string_id;
 mod subsystem;
@@ -162,8 +165,10 @@ pub use std::string::String;
 
 pub use self::{
 	entry::MAX_STRING_LENGTH,
-	error::{Utf16Error, Utf8Error},
+	error::{PatternError, Utf16Error, Utf8Error},
 	name::Name,
+	normalization::Normalization,
+	pattern::NamePattern,
 	string_id::StringId,
 	subsystem::Subsystem,
 	text::Text,
@@ -172,7 +177,8 @@ pub use self::{
 use self::{
 	allocator::Allocator,
 	entry::{Entry, DATA_OFFSET},
-	entry_hash_table::EntryHashTable,
+	entry_hash_table::{EntryHashTable, NUM_BUCKETS},
+	mapped_table::{MappedTable, FINGERPRINT_PROBE},
 	static_ref_vector::StaticRefVector,
 };
 
@@ -0,0 +1,400 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{
+	fs::File,
+	io::{self, Write},
+	path::Path,
+	str,
+};
+
+use astral_thirdparty::memmap2::Mmap;
+
+use super::{StringId, NUM_BUCKETS};
+
+const MAGIC: [u8; 8] = *b"ASTRSTRT";
+const VERSION: u32 = 3;
+const HEADER_SIZE: usize = 24;
+const RECORD_HEADER_SIZE: usize = 10;
+const RECORD_ALIGN: usize = 4;
+const NONE: u32 = u32::MAX;
+
+/// The string, whose hash identifies the hasher a table was written with.
+pub(super) const FINGERPRINT_PROBE: &str = "astral string table";
+
+/// A read-only string table, which is mapped into memory.
+///
+/// The file starts with a header containing a magic number, the format version, the number of
+/// strings and the fingerprint of the hasher. It is followed by the bucket array, the offset of every record indexed by its
+/// [`StringId`] and finally the records. A record is laid out like an `Entry`: the offset of the
+/// next record in the same bucket, the id, the length and the string data padded to four bytes.
+/// All offsets are relative to the first record and all integers are stored in little endian.
+///
+/// The buckets are computed from the lower 16 bits of the hash like in `EntryHashTable`, so a
+/// table can only be used with the hasher it was written with. The fingerprint is the hash of
+/// [`FINGERPRINT_PROBE`] and is checked when the table is opened.
+///
+/// [`FINGERPRINT_PROBE`]: constant.FINGERPRINT_PROBE.html
+pub(super) struct MappedTable {
+	map: Mmap,
+	len: u32,
+	records: usize,
+}
+
+impl MappedTable {
+	/// Maps the table at the given path into memory.
+	///
+	/// The table is validated once, lookups afterwards neither hash, nor copy any data. The table
+	/// is rejected, if it was written with a hasher with another `fingerprint`.
+	///
+	/// # Safety
+	///
+	/// The file must not be modified while the table is alive. The strings are only validated
+	/// here and are returned by [`string`] without further checks.
+	///
+	/// [`string`]: #method.string
+	pub(super) unsafe fn open<P>(path: P, fingerprint: u64) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		let file = File::open(path)?;
+		// The map is read-only, the caller guarantees that the file is not modified
+		let map = unsafe { Mmap::map(&file)? };
+
+		if map.len() < HEADER_SIZE || map[0..8] != MAGIC {
+			return Err(invalid_data("not a string table"));
+		}
+		if read_u32(&map, 8) != VERSION {
+			return Err(invalid_data("unsupported string table version"));
+		}
+		if read_u64(&map, 16) != fingerprint {
+			return Err(invalid_data("string table uses a different hasher"));
+		}
+		let len = read_u32(&map, 12);
+		let records = HEADER_SIZE + 4 * NUM_BUCKETS + 4 * len as usize;
+		if records > map.len() {
+			return Err(invalid_data("string table is truncated"));
+		}
+
+		let table = Self { map, len, records };
+		table.validate()?;
+		Ok(table)
+	}
+
+	fn validate(&self) -> io::Result<()> {
+		for id in 0..self.len {
+			let offset = self.offset(id);
+			if self.records + offset as usize + RECORD_HEADER_SIZE > self.map.len() {
+				return Err(invalid_data("record is out of bounds"));
+			}
+			if self.record_id(offset) != id {
+				return Err(invalid_data("record does not match its id"));
+			}
+			let data = self.records + offset as usize + RECORD_HEADER_SIZE;
+			let data_len = self.record_len(offset);
+			if data + data_len > self.map.len() {
+				return Err(invalid_data("string is out of bounds"));
+			}
+			if str::from_utf8(&self.map[data..data + data_len]).is_err() {
+				return Err(invalid_data("string is not valid UTF-8"));
+			}
+		}
+
+		for bucket in 0..NUM_BUCKETS {
+			let mut offset = self.head(bucket);
+			let mut remaining = self.len;
+			while offset != NONE {
+				if remaining == 0 {
+					return Err(invalid_data("bucket contains a cycle"));
+				}
+				remaining -= 1;
+				if !self.is_record(offset) {
+					return Err(invalid_data("bucket points to an invalid record"));
+				}
+				offset = self.record_next(offset);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn is_record(&self, offset: u32) -> bool {
+		self.records + offset as usize + RECORD_HEADER_SIZE <= self.map.len()
+			&& self.record_id(offset) < self.len
+			&& self.offset(self.record_id(offset)) == offset
+	}
+
+	/// Returns the number of strings stored in this table.
+	pub(super) fn len(&self) -> u32 {
+		self.len
+	}
+
+	/// Returns the size of the mapped file in bytes.
+	pub(super) fn size(&self) -> usize {
+		self.map.len()
+	}
+
+	fn head(&self, bucket: usize) -> u32 {
+		read_u32(&self.map, HEADER_SIZE + 4 * bucket)
+	}
+
+	fn offset(&self, id: u32) -> u32 {
+		read_u32(&self.map, HEADER_SIZE + 4 * NUM_BUCKETS + 4 * id as usize)
+	}
+
+	fn record_next(&self, offset: u32) -> u32 {
+		read_u32(&self.map, self.records + offset as usize)
+	}
+
+	fn record_id(&self, offset: u32) -> u32 {
+		read_u32(&self.map, self.records + offset as usize + 4)
+	}
+
+	fn record_len(&self, offset: u32) -> usize {
+		let start = self.records + offset as usize + 8;
+		u16::from_le_bytes([self.map[start], self.map[start + 1]]) as usize
+	}
+
+	fn record_data(&self, offset: u32) -> &[u8] {
+		let start = self.records + offset as usize + RECORD_HEADER_SIZE;
+		&self.map[start..start + self.record_len(offset)]
+	}
+
+	/// Searches the bucket of `hash` for the given string.
+	pub(super) fn find(&self, string: &str, hash: u16) -> Option<StringId> {
+		let mut offset = self.head(hash as usize);
+		while offset != NONE {
+			if self.record_data(offset) == string.as_bytes() {
+				return Some(StringId::from_raw_parts(self.record_id(offset)));
+			}
+			offset = self.record_next(offset);
+		}
+		None
+	}
+
+	/// Returns the string of the given id.
+	///
+	/// The id must be less than [`len`](#method.len).
+	pub(super) fn string(&self, id: StringId) -> &str {
+		debug_assert!(id.get() < self.len, "Invalid string id");
+		let data = self.record_data(self.offset(id.get()));
+		// All strings were validated when the table was opened and the file is not modified
+		unsafe { str::from_utf8_unchecked(data) }
+	}
+
+	/// Writes a table from the strings in the order of their ids.
+	///
+	/// `strings` yields the string and the lower 16 bits of its hash, `fingerprint` is the hash of
+	/// [`FINGERPRINT_PROBE`].
+	///
+	/// [`FINGERPRINT_PROBE`]: constant.FINGERPRINT_PROBE.html
+	#[allow(clippy::cast_possible_truncation)]
+	pub(super) fn write<'a, W, I>(mut writer: W, fingerprint: u64, strings: I) -> io::Result<()>
+	where
+		W: Write,
+		I: IntoIterator<Item = (&'a str, u16)>,
+	{
+		let mut buckets = vec![NONE; NUM_BUCKETS];
+		let mut tails = vec![NONE; NUM_BUCKETS];
+		let mut offsets = Vec::new();
+		let mut records = Vec::new();
+
+		for (id, (string, hash)) in strings.into_iter().enumerate() {
+			let offset = records.len();
+			if offset > NONE as usize - RECORD_HEADER_SIZE - string.len() || id >= NONE as usize {
+				return Err(invalid_data("string table is too large"));
+			}
+			let offset = offset as u32;
+			let bucket = hash as usize;
+
+			if tails[bucket] == NONE {
+				buckets[bucket] = offset;
+			} else {
+				let next = tails[bucket] as usize;
+				records[next..next + 4].copy_from_slice(&offset.to_le_bytes());
+			}
+			tails[bucket] = offset;
+			offsets.push(offset);
+
+			records.extend_from_slice(&NONE.to_le_bytes());
+			records.extend_from_slice(&(id as u32).to_le_bytes());
+			records.extend_from_slice(&(string.len() as u16).to_le_bytes());
+			records.extend_from_slice(string.as_bytes());
+			while records.len() % RECORD_ALIGN != 0 {
+				records.push(0);
+			}
+		}
+
+		writer.write_all(&MAGIC)?;
+		writer.write_all(&VERSION.to_le_bytes())?;
+		writer.write_all(&(offsets.len() as u32).to_le_bytes())?;
+		writer.write_all(&fingerprint.to_le_bytes())?;
+		for head in buckets {
+			writer.write_all(&head.to_le_bytes())?;
+		}
+		for offset in offsets {
+			writer.write_all(&offset.to_le_bytes())?;
+		}
+		writer.write_all(&records)?;
+		writer.flush()
+	}
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+	u32::from_le_bytes([
+		bytes[offset],
+		bytes[offset + 1],
+		bytes[offset + 2],
+		bytes[offset + 3],
+	])
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+	u64::from(read_u32(bytes, offset)) | u64::from(read_u32(bytes, offset + 4)) << 32
+}
+
+fn invalid_data(message: &str) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod test {
+	use std::{
+		env,
+		fs,
+		io,
+		path::{Path, PathBuf},
+		process,
+	};
+
+	use astral::{thirdparty::slog, util::hash::BuildXxh3};
+
+	use crate::{Name, Subsystem, Text};
+
+	fn table_path(name: &str) -> PathBuf {
+		env::temp_dir().join(format!("astral-{}-{}.strings", name, process::id()))
+	}
+
+	fn open(path: &Path, max_strings: usize, logger: &slog::Logger) -> io::Result<Subsystem> {
+		// Every test writes its own table and does not modify it afterwards
+		unsafe { Subsystem::from_table(path, max_strings, logger) }
+	}
+
+	fn open_with_hasher(
+		path: &Path,
+		max_strings: usize,
+		logger: &slog::Logger,
+		hasher: BuildXxh3,
+	) -> io::Result<Subsystem<BuildXxh3>> {
+		unsafe { Subsystem::from_table_with_hasher(path, max_strings, logger, hasher) }
+	}
+
+	#[test]
+	fn test_round_trip() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let path = table_path("round-trip");
+		let (foo, bar, empty) = {
+			let string_subsystem = Subsystem::new(64, &logger);
+			let foo = Text::new("foo", &string_subsystem).id();
+			let bar = Name::new("bar-10", &string_subsystem).id();
+			let empty = Text::new("", &string_subsystem).id();
+			string_subsystem
+				.write_table(fs::File::create(&path).unwrap())
+				.unwrap();
+			(foo, bar, empty)
+		};
+
+		let string_subsystem = open(&path, 64, &logger).unwrap();
+		assert_eq!(Text::new("foo", &string_subsystem).id(), foo);
+		assert_eq!(Name::new("bar-20", &string_subsystem).id(), bar);
+		assert_eq!(Text::new("", &string_subsystem).id(), empty);
+		assert_eq!(Name::new("bar-20", &string_subsystem), "bar-20");
+		assert!(Text::new("", &string_subsystem).is_empty());
+
+		let baz = Text::new("baz", &string_subsystem);
+		assert_ne!(baz.id(), foo);
+		assert_ne!(baz.id(), bar);
+		assert_ne!(baz.id(), empty);
+		assert_eq!(baz, "baz");
+		assert_eq!(baz.len(), 3);
+		assert_eq!(Text::new("baz", &string_subsystem), baz);
+
+		drop(string_subsystem);
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	#[should_panic(expected = "read-only")]
+	fn test_read_only() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let path = table_path("read-only");
+		Subsystem::new(64, &logger)
+			.write_table(fs::File::create(&path).unwrap())
+			.unwrap();
+
+		let string_subsystem = open(&path, 0, &logger).unwrap();
+		fs::remove_file(path).unwrap();
+		let _ = Text::new("foo", &string_subsystem);
+	}
+
+	#[test]
+	fn test_lookup() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let path = table_path("lookup");
+		let foo = {
+			let string_subsystem = Subsystem::new(64, &logger);
+			let foo = Text::new("foo", &string_subsystem).id();
+			string_subsystem
+				.write_table(fs::File::create(&path).unwrap())
+				.unwrap();
+			foo
+		};
+
+		let string_subsystem = open(&path, 0, &logger).unwrap();
+		fs::remove_file(path).unwrap();
+		assert_eq!(string_subsystem.lookup("foo"), Some(foo));
+		assert_eq!(string_subsystem.lookup("bar"), None);
+	}
+
+	#[test]
+	fn test_hasher_mismatch() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let path = table_path("hasher");
+		Subsystem::with_hasher(64, &logger, BuildXxh3::new(1))
+			.write_table(fs::File::create(&path).unwrap())
+			.unwrap();
+
+		let error = open(&path, 64, &logger).err().unwrap();
+		assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+		let error = open_with_hasher(&path, 64, &logger, BuildXxh3::new(2))
+			.err()
+			.unwrap();
+		assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+		assert!(open_with_hasher(&path, 64, &logger, BuildXxh3::new(1)).is_ok());
+		fs::remove_file(path).unwrap();
+	}
+
+	#[test]
+	fn test_invalid_table() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let path = table_path("invalid");
+		fs::write(&path, b"ASTRSTRT\x01\x00\x00\x00\xFF\x00\x00\x00").unwrap();
+		assert!(open(&path, 64, &logger).is_err());
+
+		fs::write(&path, b"not a string table").unwrap();
+		assert!(open(&path, 64, &logger).is_err());
+		fs::remove_file(path).unwrap();
+	}
+}
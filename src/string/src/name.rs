@@ -25,6 +25,8 @@ use std::{
 	str::{self, FromStr},
 };
 
+use astral_thirdparty::slog::{self, Key, Record, Serializer, Value};
+
 use astral_util::hash::Murmur3;
 
 use super::{StringId, Subsystem, Text, Utf16Error, Utf8Error};
@@ -70,6 +72,13 @@ where
 	///
 	/// [`Subsystem`]: struct.Subsystem.html
 	///
+	/// # Panics
+	///
+	/// Panics, if the string is not contained in the string table of a read-only [`Subsystem`]. See
+	/// [`Subsystem::from_table`].
+	///
+	/// [`Subsystem::from_table`]: struct.Subsystem.html#method.from_table
+	///
 	/// # Example
 	///
 	/// ```
@@ -85,8 +94,10 @@ where
 	where
 		T: AsRef<str>,
 	{
-		let (string, number) = Self::split_string(string.as_ref());
-		let id = system.create_string_id(string);
+		// The number is split from the normalized string, as normalization may produce digits
+		let string = system.normalize(string.as_ref());
+		let (string, number) = Self::split_string(&string);
+		let id = system.create_normalized_string_id(string);
 		unsafe { Self::from_raw_parts(id, number, system) }
 	}
 
@@ -588,6 +599,22 @@ impl<H> Display for Name<'_, H> {
 	}
 }
 
+impl<H> Value for Name<'_, H> {
+	fn serialize(
+		&self,
+		_record: &Record<'_>,
+		key: Key,
+		serializer: &mut dyn Serializer,
+	) -> slog::Result {
+		let string_part = self.string_part();
+		if let Some(number) = self.number {
+			serializer.emit_arguments(key, &format_args!("{string_part}{number}"))
+		} else {
+			serializer.emit_str(key, string_part)
+		}
+	}
+}
+
 impl<'system, H> Extend<Name<'system, H>> for String
 where
 	H: 'system,
@@ -934,4 +961,58 @@ mod test {
 		c.extend(vec![u]);
 		assert_eq!(s, c);
 	}
+
+	#[derive(Default)]
+	struct Recorder {
+		values: Vec<(Key, String)>,
+	}
+
+	impl Serializer for Recorder {
+		fn emit_arguments(&mut self, key: Key, val: &fmt::Arguments<'_>) -> slog::Result {
+			self.values.push((key, format!("args:{val}")));
+			Ok(())
+		}
+
+		fn emit_str(&mut self, key: Key, val: &str) -> slog::Result {
+			self.values.push((key, format!("str:{val}")));
+			Ok(())
+		}
+
+		fn emit_u32(&mut self, key: Key, val: u32) -> slog::Result {
+			self.values.push((key, format!("u32:{val}")));
+			Ok(())
+		}
+	}
+
+	#[test]
+	fn test_slog_value() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger);
+		let foo = Name::new("foo", &string_subsystem);
+		let bar = Name::new("bar-10", &string_subsystem);
+		let text = Text::new("baz", &string_subsystem);
+
+		let args = format_args!("");
+		let kv = slog::b!();
+		let record = slog::record!(slog::Level::Info, "", &args, kv);
+		let mut recorder = Recorder::default();
+		foo.serialize(&record, "foo", &mut recorder).unwrap();
+		bar.serialize(&record, "bar", &mut recorder).unwrap();
+		text.serialize(&record, "text", &mut recorder).unwrap();
+		text.id().serialize(&record, "id", &mut recorder).unwrap();
+		text.id()
+			.resolve(&string_subsystem)
+			.unwrap()
+			.serialize(&record, "resolved", &mut recorder)
+			.unwrap();
+
+		assert_eq!(recorder.values, [
+			("foo", String::from("str:foo")),
+			("bar", String::from("args:bar-10")),
+			("text", String::from("str:baz")),
+			("id", format!("u32:{}", text.id().get())),
+			("resolved", String::from("str:baz")),
+		]);
+		assert!(StringId::from_raw_parts(100).resolve(&string_subsystem).is_none());
+	}
 }
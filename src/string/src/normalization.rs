@@ -0,0 +1,189 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::borrow::Cow;
+
+use astral_thirdparty::unicode_normalization::{
+	is_nfc_quick,
+	is_nfkc_quick,
+	IsNormalized,
+	UnicodeNormalization,
+};
+
+/// The Unicode normalization form applied to strings before they are interned.
+///
+/// Text coming from different sources may be composed or decomposed, so visually identical strings
+/// would get different [`StringId`]s. Normalizing the input ensures, that equivalent strings share
+/// the same id.
+///
+/// [`StringId`]: struct.StringId.html
+///
+/// # Example
+///
+/// ```
+/// # use astral::thirdparty::slog;
+/// use astral::string::{self, Normalization, Text};
+///
+/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+/// let string_subsystem = string::Subsystem::new(64, &logger);
+/// let string_subsystem = string_subsystem.with_normalization(Normalization::Nfc);
+///
+/// let composed = Text::new("\u{e9}", &string_subsystem);
+/// let decomposed = Text::new("e\u{301}", &string_subsystem);
+/// assert_eq!(composed, decomposed);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Normalization {
+	/// Strings are interned as they are.
+	#[default]
+	None,
+	/// Strings are normalized to the Normalization Form C (canonical composition).
+	Nfc,
+	/// Strings are normalized to the Normalization Form KC (compatibility composition).
+	///
+	/// In addition to `Nfc`, compatibility characters like ligatures or full-width letters are
+	/// replaced by their canonical equivalent.
+	Nfkc,
+}
+
+impl Normalization {
+	pub(super) fn apply(self, string: &str) -> Cow<'_, str> {
+		match self {
+			Normalization::None => Cow::Borrowed(string),
+			Normalization::Nfc => {
+				if is_nfc_quick(string.chars()) == IsNormalized::Yes {
+					Cow::Borrowed(string)
+				} else {
+					Cow::Owned(string.nfc().collect())
+				}
+			}
+			Normalization::Nfkc => {
+				if is_nfkc_quick(string.chars()) == IsNormalized::Yes {
+					Cow::Borrowed(string)
+				} else {
+					Cow::Owned(string.nfkc().collect())
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	#![allow(clippy::non_ascii_literal)]
+
+	use astral::thirdparty::slog;
+
+	use super::*;
+	use crate::{Name, Subsystem, Text};
+
+	/// Pairs of composed and decomposed strings, which are canonically equivalent.
+	const CANONICAL: &[(&str, &str)] = &[
+		("\u{e9}", "e\u{301}"),
+		("\u{c5}ngstr\u{f6}m", "A\u{30a}ngstro\u{308}m"),
+		("Espa\u{f1}a", "Espan\u{303}a"),
+		("\u{1e69}", "s\u{323}\u{307}"),
+		(
+			"\u{d55c}\u{ad6d}\u{c5b4}",
+			"\u{1112}\u{1161}\u{11ab}\u{1100}\u{116e}\u{11a8}\u{110b}\u{1165}",
+		),
+		("\u{1f00}\u{3b9}", "\u{3b1}\u{313}\u{3b9}"),
+		("\u{c5}", "\u{212b}"),
+	];
+
+	/// Pairs of strings, which are only equivalent in compatibility normalization.
+	const COMPATIBILITY: &[(&str, &str)] = &[
+		("\u{fb01}le", "file"),
+		("\u{ff21}\u{ff22}\u{ff23}", "ABC"),
+		("x\u{b2}", "x2"),
+		("\u{2460}", "1"),
+	];
+
+	#[test]
+	fn test_apply() {
+		for (composed, decomposed) in CANONICAL {
+			assert_eq!(Normalization::None.apply(decomposed), *decomposed);
+			assert_eq!(Normalization::Nfc.apply(decomposed), *composed);
+			assert_eq!(Normalization::Nfc.apply(composed), *composed);
+			assert_eq!(Normalization::Nfkc.apply(decomposed), *composed);
+		}
+		for (compatible, canonical) in COMPATIBILITY {
+			assert_eq!(Normalization::Nfkc.apply(compatible), *canonical);
+		}
+		assert_eq!(Normalization::Nfc.apply("\u{fb01}le"), "\u{fb01}le");
+		assert!(matches!(Normalization::Nfc.apply("foo"), Cow::Borrowed(_)));
+	}
+
+	#[test]
+	fn test_nfc() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger).with_normalization(Normalization::Nfc);
+
+		for (composed, decomposed) in CANONICAL {
+			let text = Text::new(decomposed, &string_subsystem);
+			assert_eq!(text, Text::new(composed, &string_subsystem));
+			assert_eq!(text, *composed);
+			assert_eq!(
+				Text::from_utf8(decomposed.as_bytes(), &string_subsystem).unwrap(),
+				text
+			);
+			let utf16 = decomposed.encode_utf16().collect::<Vec<_>>();
+			assert_eq!(Text::from_utf16(&utf16, &string_subsystem).unwrap(), text);
+			assert_eq!(Text::from_utf16_lossy(&utf16, &string_subsystem), text);
+			assert_eq!(
+				Name::new(decomposed, &string_subsystem),
+				Name::new(composed, &string_subsystem)
+			);
+		}
+
+		for (compatible, canonical) in COMPATIBILITY {
+			assert_ne!(
+				Text::new(compatible, &string_subsystem),
+				Text::new(canonical, &string_subsystem)
+			);
+		}
+	}
+
+	#[test]
+	fn test_nfkc() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger).with_normalization(Normalization::Nfkc);
+
+		for (compatible, canonical) in COMPATIBILITY.iter().chain(CANONICAL) {
+			assert_eq!(
+				Text::new(compatible, &string_subsystem),
+				Text::new(canonical, &string_subsystem)
+			);
+		}
+
+		let name = Name::new("spawn_\u{ff11}\u{ff12}", &string_subsystem);
+		assert_eq!(name.string_part(), "spawn_");
+		assert_eq!(name.number().unwrap().get(), 12);
+	}
+
+	#[test]
+	fn test_none() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger);
+		assert_eq!(string_subsystem.normalization(), Normalization::None);
+
+		for (composed, decomposed) in CANONICAL {
+			assert_ne!(
+				Text::new(decomposed, &string_subsystem),
+				Text::new(composed, &string_subsystem)
+			);
+		}
+	}
+}
@@ -0,0 +1,570 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{
+	fmt::{self, Display, Formatter},
+	num::NonZeroU32,
+	str::{self, FromStr},
+};
+
+use super::{Name, PatternError, StringId, Subsystem, Text};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+	Literal(char),
+	Any,
+	Star,
+	Class {
+		negated: bool,
+		ranges: Vec<(char, char)>,
+	},
+	Number {
+		min: u32,
+		max: u32,
+	},
+}
+
+/// A compiled wildcard pattern for matching [`Name`]s and [`Text`]s.
+///
+/// The following syntax is supported:
+///
+/// * `?` matches exactly one character.
+/// * `*` matches any sequence of characters, including the empty one.
+/// * `[abc]` matches one of the characters in the brackets. Ranges like `[a-z]` are supported and
+///   the class is negated, if the first character is `!` or `^`.
+/// * `{min-max}` matches a decimal number between `min` and `max` (inclusive), `{n}` matches
+///   exactly the number `n`.
+/// * `\` escapes the following character.
+///
+/// Matching a `Name` uses the string part and the numeric suffix directly, the `Name` is never
+/// formatted into a [`String`].
+///
+/// [`Name`]: struct.Name.html
+/// [`Text`]: struct.Text.html
+///
+/// # Example
+///
+/// ```
+/// # use astral::thirdparty::slog;
+/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+/// use astral::string::{Name, NamePattern};
+///
+/// let doors = NamePattern::new("door_*").unwrap();
+/// assert!(doors.matches(Name::new("door_kitchen", &string_subsystem)));
+/// assert!(!doors.matches(Name::new("window_kitchen", &string_subsystem)));
+///
+/// let spawns = NamePattern::new("spawn_{1-10}").unwrap();
+/// assert!(spawns.matches(Name::new("spawn_7", &string_subsystem)));
+/// assert!(!spawns.matches(Name::new("spawn_11", &string_subsystem)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NamePattern {
+	pattern: String,
+	tokens: Vec<Token>,
+}
+
+/// The characters of a `Name` without formatting it into a `String`.
+struct Input<'a> {
+	string: &'a str,
+	digits: [u8; 10],
+	digits_start: usize,
+}
+
+impl<'a> Input<'a> {
+	fn new(string: &'a str, number: Option<NonZeroU32>) -> Self {
+		let mut digits = [0; 10];
+		let mut digits_start = digits.len();
+		if let Some(number) = number {
+			let mut number = number.get();
+			while number > 0 {
+				digits_start -= 1;
+				digits[digits_start] = b'0' + (number % 10) as u8;
+				number /= 10;
+			}
+		}
+		Self {
+			string,
+			digits,
+			digits_start,
+		}
+	}
+
+	fn len(&self) -> usize {
+		self.string.len() + self.digits.len() - self.digits_start
+	}
+
+	/// Returns the character at the byte index `pos` and the index of the next character.
+	fn next(&self, pos: usize) -> Option<(char, usize)> {
+		if pos < self.string.len() {
+			self.string[pos..]
+				.chars()
+				.next()
+				.map(|c| (c, pos + c.len_utf8()))
+		} else if pos < self.len() {
+			let digit = self.digits[self.digits_start + pos - self.string.len()];
+			Some((char::from(digit), pos + 1))
+		} else {
+			None
+		}
+	}
+}
+
+impl NamePattern {
+	/// Compiles a pattern.
+	///
+	/// # Errors
+	///
+	/// Returns [`PatternError`] if the pattern contains an unclosed character class or number
+	/// range, an invalid number range or a trailing escape character.
+	///
+	/// [`PatternError`]: struct.PatternError.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::string::NamePattern;
+	///
+	/// assert!(NamePattern::new("spawn_??").is_ok());
+	/// assert!(NamePattern::new("spawn_{10-1}").is_err());
+	/// ```
+	pub fn new(pattern: &str) -> Result<Self, PatternError> {
+		let mut tokens = Vec::new();
+		let mut chars = pattern.char_indices().peekable();
+		while let Some((position, c)) = chars.next() {
+			let token = match c {
+				'?' => Token::Any,
+				'*' => {
+					if tokens.last() == Some(&Token::Star) {
+						continue;
+					}
+					Token::Star
+				}
+				'\\' => Token::Literal(
+					chars
+						.next()
+						.ok_or_else(|| PatternError::new(position, "trailing escape character"))?
+						.1,
+				),
+				'[' => {
+					let negated = match chars.peek() {
+						Some((_, '!' | '^')) => chars.next().is_some(),
+						_ => false,
+					};
+					let mut ranges = Vec::new();
+					loop {
+						let start = match chars.next() {
+							None => {
+								return Err(PatternError::new(
+									position,
+									"unclosed character class",
+								));
+							}
+							Some((_, ']')) if !ranges.is_empty() => break,
+							Some((index, '\\')) => {
+								chars
+									.next()
+									.ok_or_else(|| {
+										PatternError::new(index, "trailing escape character")
+									})?
+									.1
+							}
+							Some((_, c)) => c,
+						};
+						let mut lookahead = chars.clone();
+						let end = match (lookahead.next(), lookahead.next()) {
+							(Some((_, '-')), Some((_, end))) if end != ']' => {
+								let _ = chars.next();
+								let _ = chars.next();
+								end
+							}
+							_ => start,
+						};
+						if start > end {
+							return Err(PatternError::new(position, "invalid character range"));
+						}
+						ranges.push((start, end));
+					}
+					Token::Class { negated, ranges }
+				}
+				'{' => {
+					let mut range = String::new();
+					loop {
+						match chars.next() {
+							None => {
+								return Err(PatternError::new(position, "unclosed number range"));
+							}
+							Some((_, '}')) => break,
+							Some((_, c)) => range.push(c),
+						}
+					}
+					let (min, max) = Self::parse_range(&range)
+						.ok_or_else(|| PatternError::new(position, "invalid number range"))?;
+					Token::Number { min, max }
+				}
+				c => Token::Literal(c),
+			};
+			tokens.push(token);
+		}
+
+		Ok(Self {
+			pattern: pattern.to_string(),
+			tokens,
+		})
+	}
+
+	fn parse_range(range: &str) -> Option<(u32, u32)> {
+		let is_number = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+		let mut parts = range.splitn(2, '-');
+		let min = parts.next().filter(|s| is_number(s))?;
+		let max = parts.next().unwrap_or(min);
+		if !is_number(max) {
+			return None;
+		}
+		let (min, max) = (u32::from_str(min).ok()?, u32::from_str(max).ok()?);
+		if min <= max { Some((min, max)) } else { None }
+	}
+
+	/// Returns the source of this pattern.
+	#[must_use]
+	pub fn as_str(&self) -> &str {
+		&self.pattern
+	}
+
+	/// Returns `true` if the given `Name` matches this pattern.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use astral::thirdparty::slog;
+	/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+	/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+	/// use astral::string::{Name, NamePattern};
+	///
+	/// let pattern = NamePattern::new("spawn_??").unwrap();
+	/// assert!(pattern.matches(Name::new("spawn_12", &string_subsystem)));
+	/// assert!(!pattern.matches(Name::new("spawn_123", &string_subsystem)));
+	/// ```
+	#[must_use]
+	pub fn matches<H>(&self, name: Name<'_, H>) -> bool {
+		self.matches_input(&Input::new(name.string_part(), name.number()))
+	}
+
+	/// Returns `true` if the given `Text` matches this pattern.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use astral::thirdparty::slog;
+	/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+	/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+	/// use astral::string::{NamePattern, Text};
+	///
+	/// let pattern = NamePattern::new("[!a-z]*").unwrap();
+	/// assert!(pattern.matches_text(Text::new("Door", &string_subsystem)));
+	/// assert!(!pattern.matches_text(Text::new("door", &string_subsystem)));
+	/// ```
+	#[must_use]
+	pub fn matches_text<H>(&self, text: Text<'_, H>) -> bool {
+		self.matches_str(text.as_str())
+	}
+
+	/// Returns `true` if the given string matches this pattern.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use astral::string::NamePattern;
+	///
+	/// let pattern = NamePattern::new("lod{0-2}.mesh").unwrap();
+	/// assert!(pattern.matches_str("lod1.mesh"));
+	/// assert!(!pattern.matches_str("lod3.mesh"));
+	/// ```
+	#[must_use]
+	pub fn matches_str(&self, string: &str) -> bool {
+		self.matches_input(&Input::new(string, None))
+	}
+
+	/// Returns an iterator over all strings interned in the `Subsystem`, which match this pattern.
+	///
+	/// A [`Name`] is interned without its numeric suffix, so only its string part is visited.
+	///
+	/// [`Name`]: struct.Name.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use astral::thirdparty::slog;
+	/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+	/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+	/// use astral::string::{NamePattern, Text};
+	///
+	/// let _ = Text::new("door_kitchen", &string_subsystem);
+	/// let _ = Text::new("window_kitchen", &string_subsystem);
+	/// let _ = Text::new("door_hall", &string_subsystem);
+	///
+	/// let pattern = NamePattern::new("door_*").unwrap();
+	/// let doors = pattern.filter(&string_subsystem).collect::<Vec<_>>();
+	/// assert_eq!(doors, ["door_kitchen", "door_hall"]);
+	/// ```
+	#[allow(clippy::cast_possible_truncation)]
+	pub fn filter<'system, H>(
+		&'system self,
+		system: &'system Subsystem<H>,
+	) -> impl Iterator<Item = Text<'system, H>> + 'system {
+		(0..system.num_strings())
+			.map(move |id| unsafe {
+				Text::from_raw_parts(StringId::from_raw_parts(id as u32), system)
+			})
+			.filter(move |text| self.matches_text(*text))
+	}
+
+	/// Matches the input segment by segment, where the segments are separated by stars.
+	///
+	/// A segment, which is followed by a star, is matched at the end as early as possible, as the
+	/// star can absorb everything in between. This way, no star has to be backtracked and the
+	/// matching is linear in the length of the input for every segment.
+	fn matches_input(&self, input: &Input<'_>) -> bool {
+		let mut segments = self.tokens.split(|token| matches!(token, Token::Star));
+		let first = segments.next().unwrap_or_default();
+		let Some(last) = segments.next_back() else {
+			return Self::match_segment(first, input, 0, true).is_some();
+		};
+
+		let Some(mut pos) = Self::match_segment(first, input, 0, false) else {
+			return false;
+		};
+		for segment in segments.filter(|segment| !segment.is_empty()) {
+			let mut start = pos;
+			let mut end = None;
+			// Every segment consumes at least one character, so a later start cannot end earlier
+			while !matches!(end, Some(end) if start >= end) {
+				if let Some(found) = Self::match_segment(segment, input, start, false) {
+					end = Some(end.map_or(found, |end: usize| end.min(found)));
+				}
+				match input.next(start) {
+					Some((_, next)) => start = next,
+					None => break,
+				}
+			}
+			match end {
+				Some(end) => pos = end,
+				None => return false,
+			}
+		}
+
+		loop {
+			if Self::match_segment(last, input, pos, true).is_some() {
+				return true;
+			}
+			match input.next(pos) {
+				Some((_, next)) => pos = next,
+				None => return false,
+			}
+		}
+	}
+
+	/// Matches `tokens`, which contain no star, at `pos` and returns the smallest end of the
+	/// match. If `to_end` is set, only a match until the end of the input is accepted.
+	fn match_segment(
+		tokens: &[Token],
+		input: &Input<'_>,
+		pos: usize,
+		to_end: bool,
+	) -> Option<usize> {
+		let Some((token, rest)) = tokens.split_first() else {
+			return (!to_end || pos == input.len()).then_some(pos);
+		};
+		match token {
+			Token::Star => unreachable!("segments are separated by stars"),
+			Token::Number { min, max } => {
+				let mut best = None;
+				let mut end = pos;
+				let mut value = 0_u64;
+				while let Some((c, next)) = input.next(end) {
+					let digit = match c.to_digit(10) {
+						Some(digit) if c.is_ascii_digit() => digit,
+						_ => break,
+					};
+					value = value * 10 + u64::from(digit);
+					if value > u64::from(*max) && value > 0 {
+						break;
+					}
+					end = next;
+					if value < u64::from(*min) {
+						continue;
+					}
+					if let Some(found) = Self::match_segment(rest, input, end, to_end) {
+						if to_end {
+							return Some(found);
+						}
+						best = Some(best.map_or(found, |best: usize| best.min(found)));
+					}
+				}
+				best
+			}
+			token => match input.next(pos) {
+				Some((c, next)) if Self::matches_char(token, c) => {
+					Self::match_segment(rest, input, next, to_end)
+				}
+				_ => None,
+			},
+		}
+	}
+
+	fn matches_char(token: &Token, c: char) -> bool {
+		match token {
+			Token::Literal(literal) => *literal == c,
+			Token::Any => true,
+			Token::Class { negated, ranges } => {
+				ranges.iter().any(|&(start, end)| start <= c && c <= end) != *negated
+			}
+			Token::Star | Token::Number { .. } => unreachable!("not a single character token"),
+		}
+	}
+}
+
+impl FromStr for NamePattern {
+	type Err = PatternError;
+
+	fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+		Self::new(pattern)
+	}
+}
+
+impl Display for NamePattern {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		Display::fmt(&self.pattern, fmt)
+	}
+}
+
+#[cfg(test)]
+mod test {
+	use astral::thirdparty::slog;
+
+	use super::*;
+
+	fn matches(pattern: &str, string: &str) -> bool {
+		NamePattern::new(pattern).unwrap().matches_str(string)
+	}
+
+	#[test]
+	fn test_wildcards() {
+		assert!(matches("", ""));
+		assert!(!matches("", "a"));
+		assert!(matches("*", ""));
+		assert!(matches("*", "door"));
+		assert!(matches("door_*", "door_"));
+		assert!(matches("door_*", "door_kitchen"));
+		assert!(!matches("door_*", "door"));
+		assert!(matches("*_kitchen", "door_kitchen"));
+		assert!(matches("d*r*n", "door_kitchen"));
+		assert!(matches("**a**", "a"));
+		assert!(matches("spawn_??", "spawn_01"));
+		assert!(!matches("spawn_??", "spawn_1"));
+		assert!(matches("?", "\u{e9}"));
+		assert!(matches("\\*\\?", "*?"));
+		assert!(!matches("\\*", "a"));
+	}
+
+	#[test]
+	fn test_classes() {
+		assert!(matches("[abc]", "b"));
+		assert!(!matches("[abc]", "d"));
+		assert!(matches("[a-z]x", "qx"));
+		assert!(matches("[!a-z]", "Q"));
+		assert!(matches("[^a-z]", "Q"));
+		assert!(!matches("[!a-z]", "q"));
+		assert!(matches("[]]", "]"));
+		assert!(matches("[a-]", "-"));
+		assert!(matches("[\\]]", "]"));
+		assert!(matches("[0-9][0-9]", "42"));
+	}
+
+	#[test]
+	fn test_number_ranges() {
+		assert!(matches("lod{0-2}", "lod0"));
+		assert!(matches("lod{0-2}", "lod2"));
+		assert!(!matches("lod{0-2}", "lod3"));
+		assert!(!matches("lod{0-2}", "lod"));
+		assert!(matches("{7}", "7"));
+		assert!(matches("{7}", "007"));
+		assert!(matches("{1-10}{1-10}", "110"));
+		assert!(matches("{1-100}_*", "42_foo"));
+		assert!(!matches("{1-100}", "4294967296"));
+		assert!(matches("{0-4294967295}", "4294967295"));
+	}
+
+	#[test]
+	fn test_backtracking() {
+		let long = "a".repeat(10_000);
+		assert!(!matches("*a*a*a*a*a*a*b", &long));
+		assert!(matches("*a*a*a*a*a*a*", &long));
+		assert!(!matches("*{0-9}*{0-9}*{0-9}*{0-9}*x", &"1".repeat(10_000)));
+		assert!(matches("*a{1-20}*b", "a5a30b"));
+		assert!(matches("*_{1-9}?", "x_10"));
+	}
+
+	#[test]
+	fn test_errors() {
+		assert_eq!(NamePattern::new("a[b").unwrap_err().position(), 1);
+		assert_eq!(NamePattern::new("ab{1-2").unwrap_err().position(), 2);
+		assert!(NamePattern::new("{2-1}").is_err());
+		assert!(NamePattern::new("{a-b}").is_err());
+		assert!(NamePattern::new("{}").is_err());
+		assert!(NamePattern::new("{99999999999}").is_err());
+		assert!(NamePattern::new("[z-a]").is_err());
+		assert!(NamePattern::new("a\\").is_err());
+		assert_eq!(
+			"door_*".parse::<NamePattern>().unwrap().to_string(),
+			"door_*"
+		);
+	}
+
+	#[test]
+	fn test_names() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger);
+		let pattern = NamePattern::new("spawn_{5-15}").unwrap();
+
+		for i in 0..20 {
+			let name = Name::new(format!("spawn_{i}"), &string_subsystem);
+			assert_eq!(pattern.matches(name), (5..=15).contains(&i), "spawn_{i}");
+		}
+		assert!(pattern.matches(Name::new("spawn_010", &string_subsystem)));
+
+		let pattern = NamePattern::new("spawn*0").unwrap();
+		assert!(pattern.matches(Name::new("spawn_10", &string_subsystem)));
+		assert!(pattern.matches(Name::new("spawn10", &string_subsystem)));
+		assert!(!pattern.matches(Name::new("spawn_1", &string_subsystem)));
+	}
+
+	#[test]
+	fn test_filter() {
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::new(64, &logger);
+		for name in &["door_a", "door_b", "window_a", "spawn_1", "spawn_2"] {
+			let _ = Name::new(name, &string_subsystem);
+		}
+
+		let pattern = NamePattern::new("*_a").unwrap();
+		let matching = pattern.filter(&string_subsystem).collect::<Vec<_>>();
+		assert_eq!(matching, ["door_a", "window_a"]);
+
+		let pattern = NamePattern::new("spawn_*").unwrap();
+		let matching = pattern.filter(&string_subsystem).collect::<Vec<_>>();
+		assert_eq!(matching, ["spawn_"]);
+	}
+}
@@ -60,6 +60,11 @@ impl<T> StaticRefVector<T> {
 		self.len.load(atomic::Ordering::Acquire)
 	}
 
+	/// Returns the number of elements the vector can hold.
+	pub(super) fn capacity(&self) -> usize {
+		self.pages.len() * ELEMENTS_PER_PAGE
+	}
+
 	/// Returns `true` if the vector is empty.
 	#[allow(unused)]
 	pub(super) fn is_empty(&self) -> bool {
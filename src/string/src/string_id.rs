@@ -15,7 +15,9 @@
 
 use std::num::NonZeroU32;
 
-use super::Subsystem;
+use astral_thirdparty::slog::{self, Key, Record, Serializer, Value};
+
+use super::{Subsystem, Text};
 
 /// An opaque struct for fast comparison between strings.
 ///
@@ -51,6 +53,13 @@ impl StringId {
 	///
 	/// [`Subsystem`]: struct.Subsystem.html
 	///
+	/// # Panics
+	///
+	/// Panics, if the string is not contained in the string table of a read-only [`Subsystem`]. See
+	/// [`Subsystem::from_table`].
+	///
+	/// [`Subsystem::from_table`]: struct.Subsystem.html#method.from_table
+	///
 	/// # Example
 	pub fn new<S>(string: S, subsystem: &Subsystem) -> Self
 	where
@@ -59,11 +68,58 @@ impl StringId {
 		subsystem.create_string_id(string)
 	}
 
+	/// Returns the [`Text`] of this `StringId` in the specified [`Subsystem`].
+	///
+	/// Returns `None`, if the `Subsystem` does not contain a string with this id. Note, that a
+	/// `StringId` from a different `Subsystem` may still resolve to an unrelated string.
+	///
+	/// This can be used to log the string of a `StringId`, as [`Text`] implements [`slog::Value`],
+	/// while the `StringId` itself is logged as integer.
+	///
+	/// [`Text`]: struct.Text.html
+	/// [`Subsystem`]: struct.Subsystem.html
+	/// [`slog::Value`]: ../thirdparty/slog/trait.Value.html
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use astral::thirdparty::slog;
+	/// use astral::thirdparty::slog::info;
+	///
+	/// use astral::string::StringId;
+	///
+	/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+	/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+	/// let id = StringId::new("foo", &string_subsystem);
+	/// let text = id.resolve(&string_subsystem).unwrap();
+	/// assert_eq!(text, "foo");
+	///
+	/// info!(logger, "resolved string"; "id" => id, "string" => text);
+	/// ```
+	pub fn resolve<H>(self, subsystem: &Subsystem<H>) -> Option<Text<'_, H>> {
+		if (self.get() as usize) < subsystem.num_strings() {
+			Some(unsafe { Text::from_raw_parts(self, subsystem) })
+		} else {
+			None
+		}
+	}
+
 	pub(crate) fn get(self) -> u32 {
 		self.0.get() - 1
 	}
 }
 
+impl Value for StringId {
+	fn serialize(
+		&self,
+		_record: &Record<'_>,
+		key: Key,
+		serializer: &mut dyn Serializer,
+	) -> slog::Result {
+		serializer.emit_u32(key, self.get())
+	}
+}
+
 #[cfg(test)]
 mod test {
 	use super::*;
@@ -14,8 +14,11 @@
 // Written by Tim Diekmann <tim.diekmann@3dvision.de>, December 2018
 
 use std::{
+	borrow::Cow,
 	fmt::{self, Debug, Formatter},
 	hash::{BuildHasher, BuildHasherDefault, Hash, Hasher},
+	io::{self, Write},
+	path::Path,
 	str,
 	sync::{
 		atomic::{self, AtomicUsize, Ordering},
@@ -23,11 +26,20 @@ use std::{
 	},
 };
 
-use astral_thirdparty::slog::{info, o, Logger};
+use astral_thirdparty::slog::{self, info, o, Logger, Record, Serializer, KV};
 
 use astral_util::hash::Murmur3;
 
-use super::{Allocator, Entry, EntryHashTable, StaticRefVector, StringId};
+use super::{
+	Allocator,
+	Entry,
+	EntryHashTable,
+	MappedTable,
+	Normalization,
+	StaticRefVector,
+	StringId,
+	FINGERPRINT_PROBE,
+};
 
 #[cfg(feature = "track-strings")]
 struct Tracker {
@@ -119,6 +131,23 @@ impl Tracker {
 	}
 }
 
+#[cfg(feature = "track-strings")]
+impl KV for Tracker {
+	fn serialize(&self, _record: &Record<'_>, serializer: &mut dyn Serializer) -> slog::Result {
+		serializer.emit_usize("strings", self.allocations())?;
+		serializer.emit_usize("memory", self.memory())?;
+		serializer.emit_usize("allocations", self.chunks())?;
+		serializer.emit_usize("average_string_length", self.average_length())
+	}
+}
+
+#[cfg(not(feature = "track-strings"))]
+impl KV for Tracker {
+	fn serialize(&self, _record: &Record<'_>, _serializer: &mut dyn Serializer) -> slog::Result {
+		Ok(())
+	}
+}
+
 /// Manages optimized string allocation.
 ///
 /// See the [module-level documentation] for more.
@@ -129,6 +158,8 @@ pub struct Subsystem<H = BuildHasherDefault<Murmur3>> {
 	allocator: Mutex<Allocator>,
 	entry_hash_table: EntryHashTable,
 	entry_reference_map: StaticRefVector<Entry>,
+	table: Option<MappedTable>,
+	normalization: Normalization,
 	build_hasher: H,
 	tracker: Tracker,
 }
@@ -149,6 +180,74 @@ impl Subsystem<BuildHasherDefault<Murmur3>> {
 	pub fn new(max_strings: usize, parent_logger: &Logger) -> Self {
 		Self::with_hasher(max_strings, parent_logger, BuildHasherDefault::default())
 	}
+
+	/// Initialize the string subsystem from a string table written by [`write_table`].
+	///
+	/// The table is mapped into memory, so strings contained in the table are neither hashed,
+	/// nor copied at startup. Strings, which are not contained in the table, are stored in a
+	/// writable overlay with the specified capacity. If `max_strings` is zero, the subsystem is
+	/// read-only.
+	///
+	/// [`write_table`]: #method.write_table
+	///
+	/// # Safety
+	///
+	/// The file must not be modified while the subsystem is alive. The strings in the table are
+	/// validated once and are handed out as references into the mapped file afterwards.
+	///
+	/// # Panics
+	///
+	/// If `max_strings` is zero, creating a string, which is not contained in the table, e.g. with
+	/// [`Text::new`], panics. Use [`lookup`] to find strings without creating them.
+	///
+	/// [`Text::new`]: struct.Text.html#method.new
+	/// [`lookup`]: #method.lookup
+	///
+	/// # Errors
+	///
+	/// Returns an error if the file could not be mapped or if it is not a valid string table.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use astral::thirdparty::slog;
+	/// # fn main() { test().unwrap() }
+	/// # fn test() -> std::io::Result<()> {
+	/// use std::fs::File;
+	///
+	/// use astral::string::{self, Text};
+	///
+	/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+	/// # let path = std::env::temp_dir().join(format!("astral-doc-{}.strings", std::process::id()));
+	/// let string_subsystem = string::Subsystem::new(64, &logger);
+	/// let foo = Text::new("foo", &string_subsystem).id();
+	/// string_subsystem.write_table(File::create(&path)?)?;
+	///
+	/// // The table is not modified while it is mapped
+	/// let string_subsystem = unsafe { string::Subsystem::from_table(&path, 64, &logger)? };
+	/// assert_eq!(Text::new("foo", &string_subsystem).id(), foo);
+	/// # drop(string_subsystem);
+	/// # std::fs::remove_file(path)?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub unsafe fn from_table<P>(
+		path: P,
+		max_strings: usize,
+		parent_logger: &Logger,
+	) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		unsafe {
+			Self::from_table_with_hasher(
+				path,
+				max_strings,
+				parent_logger,
+				BuildHasherDefault::default(),
+			)
+		}
+	}
 }
 
 impl<H> Subsystem<H>
@@ -158,6 +257,11 @@ where
 	/// Initialize the string subsystem with the specified capacity for unique strings, and a
 	/// hasher.
 	///
+	/// Any `BuildHasher` can be used, e.g. the hashers in [`astral_util::hash`]. Only the lower
+	/// 16 bits of the hashes are used to find strings.
+	///
+	/// [`astral_util::hash`]: ../util/hash/index.html
+	///
 	/// # Example
 	///
 	/// ```
@@ -165,7 +269,7 @@ where
 	/// use std::hash::BuildHasherDefault;
 	///
 	/// use astral::{
-	/// 	util::hash::Murmur3,
+	/// 	util::hash::{BuildXxh3, Murmur3},
 	/// 	string::{self, Text},
 	/// };
 	///
@@ -175,32 +279,186 @@ where
 	///
 	/// let text = Text::new("foo", &string_subsystem);
 	/// assert_eq!(text, "foo");
+	///
+	/// let string_subsystem = string::Subsystem::with_hasher(64, &logger, BuildXxh3::new(42));
+	///
+	/// let text = Text::new("foo", &string_subsystem);
+	/// assert_eq!(text, "foo");
 	/// ```
 	pub fn with_hasher(max_strings: usize, parent_logger: &Logger, hasher: H) -> Self {
+		Self::with_table(max_strings, parent_logger, hasher, None)
+	}
+
+	/// Initialize the string subsystem from a string table written by [`write_table`] and a
+	/// hasher.
+	///
+	/// The hasher has to produce the same hashes as the one used for writing the table. This is
+	/// checked by comparing the hashes of a fixed string.
+	///
+	/// See [`from_table`] for more.
+	///
+	/// [`write_table`]: #method.write_table
+	/// [`from_table`]: #method.from_table
+	///
+	/// # Safety
+	///
+	/// The file must not be modified while the subsystem is alive.
+	///
+	/// # Panics
+	///
+	/// See [`from_table`].
+	///
+	/// # Errors
+	///
+	/// Returns an error if the file could not be mapped or if it is not a valid string table. If
+	/// the table was written with another hasher, an error of kind [`InvalidData`] is returned.
+	///
+	/// [`InvalidData`]: std::io::ErrorKind::InvalidData
+	pub unsafe fn from_table_with_hasher<P>(
+		path: P,
+		max_strings: usize,
+		parent_logger: &Logger,
+		hasher: H,
+	) -> io::Result<Self>
+	where
+		P: AsRef<Path>,
+	{
+		let fingerprint = Self::hash_with(&hasher, FINGERPRINT_PROBE);
+		let table = unsafe { MappedTable::open(path, fingerprint)? };
+		Ok(Self::with_table(
+			max_strings,
+			parent_logger,
+			hasher,
+			Some(table),
+		))
+	}
+
+	fn with_table(
+		max_strings: usize,
+		parent_logger: &Logger,
+		hasher: H,
+		table: Option<MappedTable>,
+	) -> Self {
 		let log = parent_logger.new(o!("subsystem" => "string"));
 		let (entry_hash_table, table_memory, table_chunks) = EntryHashTable::new();
 		let (entry_reference_map, map_memory, map_chunks) = StaticRefVector::new(max_strings);
 		info!(log, "initializing"; "version" => env!("CARGO_PKG_VERSION"));
+		if let Some(table) = &table {
+			info!(log, "mapped string table"; "strings" => table.len(), "size" => table.size());
+		}
 		Self {
 			log,
 			allocator: Mutex::new(Allocator::default()),
 			entry_hash_table,
 			entry_reference_map,
+			table,
+			normalization: Normalization::None,
 			build_hasher: hasher,
 			tracker: Tracker::new(table_memory + map_memory, table_chunks + map_chunks),
 		}
 	}
 
+	fn hash(&self, string: &str) -> u64 {
+		Self::hash_with(&self.build_hasher, string)
+	}
+
+	fn hash_with(build_hasher: &H, string: &str) -> u64 {
+		let mut hasher = build_hasher.build_hasher();
+		Hash::hash_slice(string.as_bytes(), &mut hasher);
+		hasher.finish()
+	}
+
+	/// Writes all strings of this subsystem into a string table, which can be loaded with
+	/// [`from_table`].
+	///
+	/// The strings keep their [`StringId`]s, so ids stored alongside the table remain valid.
+	///
+	/// [`from_table`]: #method.from_table
+	/// [`StringId`]: struct.StringId.html
+	///
+	/// # Errors
+	///
+	/// Returns an error if writing fails or if the strings exceed the size limit of a table.
+	#[allow(clippy::cast_possible_truncation)]
+	pub fn write_table<W>(&self, writer: W) -> io::Result<()>
+	where
+		W: Write,
+	{
+		let len = self.num_strings();
+		MappedTable::write(
+			writer,
+			self.hash(FINGERPRINT_PROBE),
+			(0..len).map(|id| {
+				let string = self.string(StringId::from_raw_parts(id as u32));
+				(string, self.hash(string) as u16)
+			}),
+		)
+	}
+
+	/// Returns the id of the given string, if it is contained in the string table or was created
+	/// before.
+	///
+	/// Unlike [`StringId::new`], the string is never added, so this also works for a read-only
+	/// subsystem created by [`from_table`] with `max_strings` set to zero.
+	///
+	/// [`StringId::new`]: struct.StringId.html#method.new
+	/// [`from_table`]: #method.from_table
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use astral::thirdparty::slog;
+	/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+	/// # let string_subsystem = astral::string::Subsystem::new(64, &logger);
+	/// use astral::string::StringId;
+	///
+	/// let id = StringId::new("foo", &string_subsystem);
+	/// assert_eq!(string_subsystem.lookup("foo"), Some(id));
+	/// assert_eq!(string_subsystem.lookup("bar"), None);
+	/// ```
+	#[allow(clippy::cast_possible_truncation)]
+	pub fn lookup<T>(&self, string: T) -> Option<StringId>
+	where
+		T: AsRef<str>,
+	{
+		let string = self.normalize(string.as_ref());
+		let hash = self.hash(&string) as u16;
+		if let Some(table) = &self.table {
+			if let Some(id) = table.find(&string, hash) {
+				return Some(id);
+			}
+		}
+		self.entry_hash_table
+			.find(&string, hash)
+			.map(|entry| StringId::from_raw_parts(entry.id().get() + self.table_len()))
+	}
+
 	pub(crate) fn create_string_id<T>(&self, string: T) -> StringId
 	where
 		T: AsRef<str>,
 	{
-		let string = string.as_ref();
-		let mut hasher = self.build_hasher.build_hasher();
-		Hash::hash_slice(string.as_bytes(), &mut hasher);
+		self.create_normalized_string_id(&self.normalize(string.as_ref()))
+	}
+
+	/// Creates the id of a string, which was already normalized with [`normalize`].
+	///
+	/// [`normalize`]: #method.normalize
+	#[allow(clippy::cast_possible_truncation)]
+	pub(crate) fn create_normalized_string_id(&self, string: &str) -> StringId {
+		let hash = self.hash(string);
+		if let Some(table) = &self.table {
+			if let Some(id) = table.find(string, hash as u16) {
+				return id;
+			}
+			assert!(
+				self.entry_reference_map.capacity() > 0,
+				"The string subsystem is read-only, \"{}\" is not contained in the string table",
+				string
+			);
+		}
 		let (id, memory, chunks, allocated) = self.entry_hash_table.find_or_insert(
 			string,
-			hasher.finish(),
+			hash,
 			&self.entry_reference_map,
 			&self.allocator,
 			self.logger(),
@@ -219,11 +477,43 @@ where
 				.is_null(),
 			"Invalid pointer"
 		);
-		id
+		StringId::from_raw_parts(id.get() + self.table_len())
 	}
 }
 
 impl<H> Subsystem<H> {
+	/// Sets the Unicode normalization form, which is applied to strings before they are interned.
+	///
+	/// This should be set before the first string is created. Strings created with a previous
+	/// normalization form are not normalized retroactively.
+	///
+	/// # Example
+	///
+	/// ```
+	/// # use astral::thirdparty::slog;
+	/// use astral::string::{self, Normalization, Text};
+	///
+	/// # let logger = slog::Logger::root(slog::Discard, slog::o!());
+	/// let string_subsystem = string::Subsystem::new(64, &logger);
+	/// let string_subsystem = string_subsystem.with_normalization(Normalization::Nfkc);
+	///
+	/// assert_eq!(Text::new("\u{fb01}le", &string_subsystem), "file");
+	/// ```
+	#[must_use]
+	pub fn with_normalization(mut self, normalization: Normalization) -> Self {
+		self.normalization = normalization;
+		self
+	}
+
+	/// Returns the Unicode normalization form applied to new strings.
+	pub fn normalization(&self) -> Normalization {
+		self.normalization
+	}
+
+	pub(super) fn normalize<'s>(&self, string: &'s str) -> Cow<'s, str> {
+		self.normalization.apply(string)
+	}
+
 	/// Returns the used memory.
 	///
 	/// Requires the `track-strings` feature to be enabled.
@@ -275,7 +565,17 @@ impl<H> Subsystem<H> {
 		&self.log
 	}
 
-	pub(super) fn string(&self, id: StringId) -> &str {
+	fn table_len(&self) -> u32 {
+		self.table.as_ref().map_or(0, MappedTable::len)
+	}
+
+	/// Returns the number of unique strings in this subsystem, including the string table.
+	pub(super) fn num_strings(&self) -> usize {
+		self.table_len() as usize + self.entry_reference_map.len()
+	}
+
+	fn entry(&self, id: StringId) -> *const Entry {
+		let id = StringId::from_raw_parts(id.get() - self.table_len());
 		debug_assert!(
 			!self
 				.entry_reference_map
@@ -284,31 +584,28 @@ impl<H> Subsystem<H> {
 				.is_null(),
 			"Index is null"
 		);
-		unsafe { (*self.entry_reference_map.get_unchecked(id)).as_str() }
+		unsafe { self.entry_reference_map.get_unchecked(id) }
+	}
+
+	pub(super) fn string(&self, id: StringId) -> &str {
+		match &self.table {
+			Some(table) if id.get() < table.len() => table.string(id),
+			_ => unsafe { (*self.entry(id)).as_str() },
+		}
 	}
 
 	pub(super) fn is_empty(&self, id: StringId) -> bool {
-		debug_assert!(
-			!self
-				.entry_reference_map
-				.get(id)
-				.expect("Invalid string id")
-				.is_null(),
-			"Index is null"
-		);
-		unsafe { (*self.entry_reference_map.get_unchecked(id)).is_empty() }
+		match &self.table {
+			Some(table) if id.get() < table.len() => table.string(id).is_empty(),
+			_ => unsafe { (*self.entry(id)).is_empty() },
+		}
 	}
 
 	pub(super) fn len(&self, id: StringId) -> usize {
-		debug_assert!(
-			!self
-				.entry_reference_map
-				.get(id)
-				.expect("Invalid string id")
-				.is_null(),
-			"Index is null"
-		);
-		unsafe { (*self.entry_reference_map.get_unchecked(id)).len() as usize }
+		match &self.table {
+			Some(table) if id.get() < table.len() => table.string(id).len(),
+			_ => unsafe { (*self.entry(id)).len() as usize },
+		}
 	}
 }
 
@@ -329,17 +626,59 @@ impl<H> Debug for Subsystem<H> {
 
 impl<H> Drop for Subsystem<H> {
 	fn drop(&mut self) {
-		#[cfg(feature = "track-strings")]
-		info!(self.logger(), "shutting down";
-			"strings" => self.strings_allocated(),
-			"memory" => self.used_memory(),
-			"allocations" => self.allocations(),
-			"average_string_length" => self.average_string_length(),
-		);
-		#[cfg(not(feature = "track-strings"))]
-		info!(self.logger(), "shutting down");
+		info!(self.logger(), "shutting down"; &self.tracker);
 	}
 }
 
 unsafe impl<H> Send for Subsystem<H> {}
 unsafe impl<H> Sync for Subsystem<H> {}
+
+#[cfg(test)]
+mod test {
+	use std::hash::BuildHasher;
+
+	use astral::thirdparty::slog;
+	use astral_util::hash::{
+		BuildFnv1a,
+		BuildMurmur3,
+		BuildMurmur3_128,
+		BuildSipHash24,
+		BuildXxHash64,
+		BuildXxh3,
+	};
+
+	use super::Subsystem;
+	use crate::{Name, Text};
+
+	fn intern_strings<H>(hasher: H)
+	where
+		H: BuildHasher,
+	{
+		let logger = slog::Logger::root(slog::Discard, slog::o!());
+		let string_subsystem = Subsystem::with_hasher(64, &logger, hasher);
+
+		let strings = (0..32).map(|i| format!("string-{i}")).collect::<Vec<_>>();
+		let texts = strings
+			.iter()
+			.map(|string| Text::new(string, &string_subsystem))
+			.collect::<Vec<_>>();
+		for (string, text) in strings.iter().zip(&texts) {
+			assert_eq!(text, string.as_str());
+			assert_eq!(Text::new(string, &string_subsystem).id(), text.id());
+		}
+		assert_eq!(string_subsystem.num_strings(), strings.len());
+
+		let name = Name::new("foo-10", &string_subsystem);
+		assert_eq!(name, "foo-10");
+	}
+
+	#[test]
+	fn test_hashers() {
+		intern_strings(BuildFnv1a::default());
+		intern_strings(BuildMurmur3::new(42));
+		intern_strings(BuildMurmur3_128::new(42));
+		intern_strings(BuildSipHash24::random());
+		intern_strings(BuildXxHash64::new(42));
+		intern_strings(BuildXxh3::new(42));
+	}
+}
@@ -25,6 +25,8 @@ use std::{
 	str,
 };
 
+use astral_thirdparty::slog::{self, Key, Record, Serializer, Value};
+
 use astral_util::hash::Murmur3;
 
 use super::{Name, StringId, Subsystem, Utf16Error, Utf8Error};
@@ -89,6 +91,13 @@ where
 	///
 	/// [`Subsystem`]: struct.Subsystem.html
 	///
+	/// # Panics
+	///
+	/// Panics, if the string is not contained in the string table of a read-only [`Subsystem`]. See
+	/// [`Subsystem::from_table`].
+	///
+	/// [`Subsystem::from_table`]: struct.Subsystem.html#method.from_table
+	///
 	/// # Example
 	///
 	/// ```
@@ -534,6 +543,17 @@ impl<H> Display for Text<'_, H> {
 	}
 }
 
+impl<H> Value for Text<'_, H> {
+	fn serialize(
+		&self,
+		_record: &Record<'_>,
+		key: Key,
+		serializer: &mut dyn Serializer,
+	) -> slog::Result {
+		serializer.emit_str(key, self.as_str())
+	}
+}
+
 macro_rules! impl_index {
 	($ty:ty) => {
 		impl<H> Index<$ty> for Text<'_, H> {
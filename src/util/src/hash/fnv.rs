@@ -0,0 +1,99 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::hash::{BuildHasherDefault, Hasher};
+
+/// An implementation of the 64-bit [FNV-1a Hash].
+///
+/// FNV-1a processes one byte at a time and has no setup or finalization cost, which makes it fast
+/// for tiny keys like integers or short identifiers. It is not resistant against collision
+/// attacks.
+///
+/// [FNV-1a Hash]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+///
+/// # Example
+///
+/// ```
+/// use std::hash::Hasher;
+///
+/// use astral::util::hash::Fnv1a;
+///
+/// let mut hasher = Fnv1a::default();
+/// hasher.write(b"foobar");
+/// assert_eq!(hasher.finish(), 0x8594_4171_F739_67E8);
+/// ```
+#[derive(Debug, Clone)]
+#[allow(missing_copy_implementations)]
+pub struct Fnv1a {
+	state: u64,
+}
+
+/// Creates [`Fnv1a`] hashers.
+///
+/// [`Fnv1a`]: struct.Fnv1a.html
+pub type BuildFnv1a = BuildHasherDefault<Fnv1a>;
+
+impl Fnv1a {
+	const OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+	const PRIME: u64 = 0x0000_0100_0000_01B3;
+}
+
+impl Default for Fnv1a {
+	fn default() -> Self {
+		Self {
+			state: Self::OFFSET_BASIS,
+		}
+	}
+}
+
+impl Hasher for Fnv1a {
+	fn finish(&self) -> u64 {
+		self.state
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		for byte in bytes {
+			self.state ^= u64::from(*byte);
+			self.state = self.state.wrapping_mul(Self::PRIME);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BuildFnv1a, Fnv1a};
+	use std::hash::{BuildHasher, Hasher};
+
+	fn hash(bytes: &[u8]) -> u64 {
+		let mut hasher = Fnv1a::default();
+		hasher.write(bytes);
+		hasher.finish()
+	}
+
+	#[test]
+	fn test_vectors() {
+		assert_eq!(hash(b""), 0xCBF2_9CE4_8422_2325);
+		assert_eq!(hash(b"a"), 0xAF63_DC4C_8601_EC8C);
+		assert_eq!(hash(b"foobar"), 0x8594_4171_F739_67E8);
+	}
+
+	#[test]
+	fn test_build_hasher() {
+		let mut hasher = BuildFnv1a::default().build_hasher();
+		hasher.write(b"foo");
+		hasher.write(b"bar");
+		assert_eq!(hasher.finish(), hash(b"foobar"));
+	}
+}
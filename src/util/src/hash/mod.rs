@@ -14,8 +14,35 @@
 // Written by Tim Diekmann <tim.diekmann@3dvision.de>, November 2018
 
 //! Hashing utilities and hashers.
+//!
+//! - [`Fnv1a`] for tiny keys like integers or short identifiers.
+//! - [`Murmur3`] and [`Murmur3_128`] as general purpose hashers, `Murmur3` is the default hasher
+//!   of the string subsystem.
+//! - [`XxHash64`] and [`Xxh3`] for large buffers like asset content.
+//! - [`SipHash24`] with a secret key for maps, which are fed by network or user data.
+//! - [`NopHasher`] for keys, which are hashes already.
+//!
+//! Each hasher except `NopHasher` has a matching `BuildHasher`, which can be passed to
+//! `HashMap::with_hasher` or `string::Subsystem::with_hasher`.
+//!
+//! [`Fnv1a`]: struct.Fnv1a.html
+//! [`Murmur3`]: struct.Murmur3.html
+//! [`Murmur3_128`]: struct.Murmur3_128.html
+//! [`XxHash64`]: struct.XxHash64.html
+//! [`Xxh3`]: struct.Xxh3.html
+//! [`SipHash24`]: struct.SipHash24.html
+//! [`NopHasher`]: struct.NopHasher.html
 
+mod fnv;
 mod murmur3;
 mod nop_hasher;
+mod sip;
+mod xxhash;
 
-pub use self::{murmur3::Murmur3, nop_hasher::NopHasher};
+pub use self::{
+	fnv::{BuildFnv1a, Fnv1a},
+	murmur3::{BuildMurmur3, BuildMurmur3_128, Murmur3, Murmur3_128},
+	nop_hasher::NopHasher,
+	sip::{BuildSipHash24, SipHash24},
+	xxhash::{BuildXxHash64, BuildXxh3, XxHash64, Xxh3},
+};
@@ -13,15 +13,19 @@
 // limitations under the License.
 // Written by Tim Diekmann <tim.diekmann@3dvision.de>, November 2018
 
-use std::{hash::Hasher, u32};
+use std::hash::{BuildHasher, Hasher};
 
-/// An implementation of the [Murmur3 Hash].
+/// An implementation of the 32-bit [Murmur3 Hash] for x86 (`MurmurHash3_x86_32`).
 ///
-/// [Murmur3 Hash]: https://en.wikipedia.org/wiki/MurmurHash#MurmurHash3
-///
-/// # Panics
+/// The hasher buffers incomplete blocks across calls to [`write`], so the result does not depend
+/// on how the input is split up. The hash is finalized in [`finish`]. Use [`with_seed`] or
+/// [`BuildMurmur3`] to hash with a seed other than 0.
 ///
-/// Panics if values with a size greater than 8 bytes are passed in.
+/// [Murmur3 Hash]: https://en.wikipedia.org/wiki/MurmurHash#MurmurHash3
+/// [`write`]: #method.write
+/// [`finish`]: #method.finish
+/// [`with_seed`]: #method.with_seed
+/// [`BuildMurmur3`]: struct.BuildMurmur3.html
 ///
 /// # Example
 ///
@@ -33,11 +37,19 @@ use std::{hash::Hasher, u32};
 /// let mut hasher = Murmur3::default();
 /// Hash::hash_slice("Hello World!".as_bytes(), &mut hasher);
 /// assert_eq!(hasher.finish(), 3691591037);
+///
+/// let mut hasher = Murmur3::default();
+/// hasher.write(b"Hello ");
+/// hasher.write(b"World!");
+/// assert_eq!(hasher.finish(), 3691591037);
 /// ```
 #[derive(Debug, Clone, Default)]
 #[allow(missing_copy_implementations)]
 pub struct Murmur3 {
-	seed: u32,
+	state: u32,
+	tail: [u8; 4],
+	tail_len: usize,
+	len: u32,
 }
 
 impl Murmur3 {
@@ -48,49 +60,426 @@ impl Murmur3 {
 	const R1: u32 = 15;
 	const R2: u32 = 13;
 
-	fn write_chunk(&mut self, chunk: [u8; 4]) {
-		// ToDo(#4): Use u32::from_ne_bytes
-		#[cfg(not(unstable))]
-		let mut k = unsafe { std::mem::transmute::<_, u32>(chunk) }.to_le();
-		#[cfg(unstable)]
-		let mut k = u32::from_ne_bytes(chunk).to_le();
+	/// Creates a hasher with the given seed.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use std::hash::Hasher;
+	///
+	/// use astral::util::hash::Murmur3;
+	///
+	/// let mut hasher = Murmur3::with_seed(1234);
+	/// hasher.write(b"Hello, world!");
+	/// assert_eq!(hasher.finish(), 0xFAF6_CDB3);
+	/// ```
+	#[must_use]
+	pub fn with_seed(seed: u32) -> Self {
+		Self {
+			state: seed,
+			..Self::default()
+		}
+	}
 
+	fn mix_k(mut k: u32) -> u32 {
 		k = u32::wrapping_mul(k, Self::C1);
 		k = u32::rotate_left(k, Self::R1);
-		k = u32::wrapping_mul(k, Self::C2);
+		u32::wrapping_mul(k, Self::C2)
+	}
+
+	fn write_block(&mut self, block: [u8; 4]) {
+		self.state ^= Self::mix_k(u32::from_le_bytes(block));
+		self.state = u32::rotate_left(self.state, Self::R2);
+		self.state = u32::wrapping_mul(self.state, Self::M);
+		self.state = u32::wrapping_add(self.state, Self::N);
+	}
 
-		self.seed ^= k;
+	fn fmix(mut h: u32) -> u32 {
+		h ^= h >> 16;
+		h = u32::wrapping_mul(h, 0x85EB_CA6B);
+		h ^= h >> 13;
+		h = u32::wrapping_mul(h, 0xC2B2_AE35);
+		h ^ (h >> 16)
 	}
 }
 
 impl Hasher for Murmur3 {
 	fn finish(&self) -> u64 {
-		self.seed.into()
+		let mut h = self.state;
+		if self.tail_len > 0 {
+			let mut k = [0; 4];
+			k[..self.tail_len].copy_from_slice(&self.tail[..self.tail_len]);
+			h ^= Self::mix_k(u32::from_le_bytes(k));
+		}
+		Self::fmix(h ^ self.len).into()
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	fn write(&mut self, mut bytes: &[u8]) {
+		// The reference implementation mixes in the length modulo 2^32
+		self.len = self.len.wrapping_add(bytes.len() as u32);
+
+		if self.tail_len > 0 {
+			let n = bytes.len().min(4 - self.tail_len);
+			self.tail[self.tail_len..self.tail_len + n].copy_from_slice(&bytes[..n]);
+			self.tail_len += n;
+			bytes = &bytes[n..];
+			if self.tail_len < 4 {
+				return;
+			}
+			self.write_block(self.tail);
+			self.tail_len = 0;
+		}
+
+		let mut blocks = bytes.chunks_exact(4);
+		for block in &mut blocks {
+			self.write_block([block[0], block[1], block[2], block[3]]);
+		}
+		let remainder = blocks.remainder();
+		self.tail[..remainder.len()].copy_from_slice(remainder);
+		self.tail_len = remainder.len();
+	}
+}
+
+/// Creates [`Murmur3`] hashers with a fixed seed.
+///
+/// [`Murmur3`]: struct.Murmur3.html
+///
+/// # Example
+///
+/// ```
+/// use std::{collections::HashMap, hash::BuildHasher};
+///
+/// use astral::util::hash::BuildMurmur3;
+///
+/// let build_hasher = BuildMurmur3::new(1234);
+/// assert_eq!(build_hasher.hash_one(42), build_hasher.hash_one(42));
+///
+/// let mut map = HashMap::with_hasher(build_hasher);
+/// map.insert("cube", 1);
+/// assert_eq!(map["cube"], 1);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BuildMurmur3 {
+	seed: u32,
+}
+
+impl BuildMurmur3 {
+	/// Creates a `BuildHasher` for hashers with the given seed.
+	#[must_use]
+	pub fn new(seed: u32) -> Self {
+		Self { seed }
+	}
+
+	/// Returns the seed of the created hashers.
+	#[must_use]
+	pub fn seed(self) -> u32 {
+		self.seed
+	}
+}
+
+impl BuildHasher for BuildMurmur3 {
+	type Hasher = Murmur3;
+
+	fn build_hasher(&self) -> Murmur3 {
+		Murmur3::with_seed(self.seed)
+	}
+}
+
+/// An implementation of the 128-bit [Murmur3 Hash] for x64 (`MurmurHash3_x64_128`).
+///
+/// Like [`Murmur3`], the hasher buffers incomplete blocks across calls to [`write`]. [`finish`]
+/// returns the lower 64 bits of the hash, the full hash is returned by [`finish_128`]. Use
+/// [`with_seed`] or [`BuildMurmur3_128`] to hash with a seed other than 0.
+///
+/// [Murmur3 Hash]: https://en.wikipedia.org/wiki/MurmurHash#MurmurHash3
+/// [`Murmur3`]: struct.Murmur3.html
+/// [`write`]: #method.write
+/// [`finish`]: #method.finish
+/// [`finish_128`]: #method.finish_128
+/// [`with_seed`]: #method.with_seed
+/// [`BuildMurmur3_128`]: struct.BuildMurmur3_128.html
+///
+/// # Example
+///
+/// ```
+/// use std::hash::Hasher;
+///
+/// use astral::util::hash::Murmur3_128;
+///
+/// let mut hasher = Murmur3_128::default();
+/// hasher.write(b"The quick brown fox jumps over the lazy dog");
+/// assert_eq!(hasher.finish_128(), 0x7A43_3CA9_C49A_9347_E34B_BC7B_BC07_1B6C);
+/// assert_eq!(hasher.finish(), 0xE34B_BC7B_BC07_1B6C);
+/// ```
+#[derive(Debug, Clone, Default)]
+#[allow(missing_copy_implementations)]
+pub struct Murmur3_128 {
+	h1: u64,
+	h2: u64,
+	tail: [u8; 16],
+	tail_len: usize,
+	len: u64,
+}
+
+impl Murmur3_128 {
+	const C1: u64 = 0x87C3_7B91_1142_53D5;
+	const C2: u64 = 0x4CF5_AD43_2745_937F;
+
+	/// Creates a hasher with the given seed.
+	#[must_use]
+	pub fn with_seed(seed: u32) -> Self {
+		Self {
+			h1: seed.into(),
+			h2: seed.into(),
+			..Self::default()
+		}
 	}
 
+	/// Returns the full 128-bit hash of the values written so far.
+	///
+	/// The first 8 bytes of the reference output are the lower 64 bits, the last 8 bytes are the
+	/// upper 64 bits, both in little-endian byte order.
+	#[must_use]
+	pub fn finish_128(&self) -> u128 {
+		let (mut h1, mut h2) = (self.h1, self.h2);
+		if self.tail_len > 0 {
+			let mut k = [0; 16];
+			k[..self.tail_len].copy_from_slice(&self.tail[..self.tail_len]);
+			let (k1, k2) = Self::split(k);
+			h2 ^= Self::mix_k2(k2);
+			h1 ^= Self::mix_k1(k1);
+		}
+
+		h1 ^= self.len;
+		h2 ^= self.len;
+		h1 = h1.wrapping_add(h2);
+		h2 = h2.wrapping_add(h1);
+		h1 = Self::fmix(h1);
+		h2 = Self::fmix(h2);
+		h1 = h1.wrapping_add(h2);
+		h2 = h2.wrapping_add(h1);
+
+		u128::from(h2) << 64 | u128::from(h1)
+	}
+
+	fn split(block: [u8; 16]) -> (u64, u64) {
+		let mut k1 = [0; 8];
+		let mut k2 = [0; 8];
+		k1.copy_from_slice(&block[..8]);
+		k2.copy_from_slice(&block[8..]);
+		(u64::from_le_bytes(k1), u64::from_le_bytes(k2))
+	}
+
+	fn mix_k1(mut k1: u64) -> u64 {
+		k1 = k1.wrapping_mul(Self::C1);
+		k1 = k1.rotate_left(31);
+		k1.wrapping_mul(Self::C2)
+	}
+
+	fn mix_k2(mut k2: u64) -> u64 {
+		k2 = k2.wrapping_mul(Self::C2);
+		k2 = k2.rotate_left(33);
+		k2.wrapping_mul(Self::C1)
+	}
+
+	fn write_block(&mut self, block: [u8; 16]) {
+		let (k1, k2) = Self::split(block);
+
+		self.h1 ^= Self::mix_k1(k1);
+		self.h1 = self.h1.rotate_left(27);
+		self.h1 = self.h1.wrapping_add(self.h2);
+		self.h1 = self.h1.wrapping_mul(5).wrapping_add(0x52DC_E729);
+
+		self.h2 ^= Self::mix_k2(k2);
+		self.h2 = self.h2.rotate_left(31);
+		self.h2 = self.h2.wrapping_add(self.h1);
+		self.h2 = self.h2.wrapping_mul(5).wrapping_add(0x3849_5AB5);
+	}
+
+	fn fmix(mut k: u64) -> u64 {
+		k ^= k >> 33;
+		k = k.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+		k ^= k >> 33;
+		k = k.wrapping_mul(0xC4CE_B9FE_1A85_EC53);
+		k ^ (k >> 33)
+	}
+}
+
+impl Hasher for Murmur3_128 {
 	#[allow(clippy::cast_possible_truncation)]
-	fn write(&mut self, bytes: &[u8]) {
-		for chunk in bytes.chunks(4) {
-			match chunk.len() {
-				1 => self.write_chunk([chunk[0], 0, 0, 0]),
-				2 => self.write_chunk([chunk[0], chunk[1], 0, 0]),
-				3 => self.write_chunk([chunk[0], chunk[1], chunk[2], 0]),
-				4 => {
-					self.write_chunk([chunk[0], chunk[1], chunk[2], chunk[3]]);
-					self.seed = u32::rotate_left(self.seed, Self::R2);
-					self.seed = u32::wrapping_mul(self.seed, Self::M);
-					self.seed = u32::wrapping_add(self.seed, Self::N);
-				}
-				_ => unreachable!("chunk size is not 4"),
+	fn finish(&self) -> u64 {
+		self.finish_128() as u64
+	}
+
+	fn write(&mut self, mut bytes: &[u8]) {
+		self.len = self.len.wrapping_add(bytes.len() as u64);
+
+		if self.tail_len > 0 {
+			let n = bytes.len().min(16 - self.tail_len);
+			self.tail[self.tail_len..self.tail_len + n].copy_from_slice(&bytes[..n]);
+			self.tail_len += n;
+			bytes = &bytes[n..];
+			if self.tail_len < 16 {
+				return;
 			}
+			self.write_block(self.tail);
+			self.tail_len = 0;
+		}
+
+		let mut blocks = bytes.chunks_exact(16);
+		for block in &mut blocks {
+			let mut buffer = [0; 16];
+			buffer.copy_from_slice(block);
+			self.write_block(buffer);
 		}
+		let remainder = blocks.remainder();
+		self.tail[..remainder.len()].copy_from_slice(remainder);
+		self.tail_len = remainder.len();
+	}
+}
+
+/// Creates [`Murmur3_128`] hashers with a fixed seed.
+///
+/// [`Murmur3_128`]: struct.Murmur3_128.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BuildMurmur3_128 {
+	seed: u32,
+}
+
+impl BuildMurmur3_128 {
+	/// Creates a `BuildHasher` for hashers with the given seed.
+	#[must_use]
+	pub fn new(seed: u32) -> Self {
+		Self { seed }
+	}
+
+	/// Returns the seed of the created hashers.
+	#[must_use]
+	pub fn seed(self) -> u32 {
+		self.seed
+	}
+}
+
+impl BuildHasher for BuildMurmur3_128 {
+	type Hasher = Murmur3_128;
+
+	fn build_hasher(&self) -> Murmur3_128 {
+		Murmur3_128::with_seed(self.seed)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BuildMurmur3, BuildMurmur3_128, Murmur3, Murmur3_128};
+	use std::hash::{BuildHasher, Hasher};
+
+	fn hash_32(bytes: &[u8], seed: u32) -> u32 {
+		let mut hasher = Murmur3::with_seed(seed);
+		hasher.write(bytes);
+		#[allow(clippy::cast_possible_truncation)]
+		let hash = hasher.finish() as u32;
+		hash
+	}
+
+	fn hash_128(bytes: &[u8], seed: u32) -> u128 {
+		let mut hasher = Murmur3_128::with_seed(seed);
+		hasher.write(bytes);
+		hasher.finish_128()
+	}
+
+	/// The verification test of `SMHasher`: hashes the keys `[]`, `[0]`, `[0, 1]`, ...,
+	/// `[0, ..., 254]` with the seeds `256 - len` and hashes the concatenated results with the
+	/// seed 0. The result is the first 4 bytes of the final hash.
+	fn verification<F>(hash_size: usize, hash: F) -> u32
+	where
+		F: Fn(&[u8], u32) -> Vec<u8>,
+	{
+		let key = (0..=255).collect::<Vec<u8>>();
+		let mut hashes = Vec::with_capacity(256 * hash_size);
+		for len in 0..256 {
+			#[allow(clippy::cast_possible_truncation)]
+			hashes.extend(hash(&key[..len], 256 - len as u32));
+		}
+		let result = hash(&hashes, 0);
+		u32::from_le_bytes([result[0], result[1], result[2], result[3]])
+	}
+
+	#[test]
+	fn test_verification() {
+		let verification_32 =
+			verification(4, |bytes, seed| hash_32(bytes, seed).to_le_bytes().to_vec());
+		assert_eq!(verification_32, 0xB0F5_7EE3);
+
+		let verification_128 = verification(16, |bytes, seed| {
+			hash_128(bytes, seed).to_le_bytes().to_vec()
+		});
+		assert_eq!(verification_128, 0x6384_BA69);
+	}
+
+	#[test]
+	fn test_vectors_32() {
+		assert_eq!(hash_32(b"", 0), 0);
+		assert_eq!(hash_32(b"", 1), 0x514E_28B7);
+		assert_eq!(hash_32(b"", 0xFFFF_FFFF), 0x81F1_6F39);
+		assert_eq!(hash_32(&[0xFF, 0xFF, 0xFF, 0xFF], 0), 0x7629_3B50);
+		assert_eq!(hash_32(&[0x21, 0x43, 0x65, 0x87], 0), 0xF55B_516B);
+		assert_eq!(hash_32(&[0x21, 0x43, 0x65, 0x87], 0x5082_EDEE), 0x2362_F9DE);
+		assert_eq!(hash_32(&[0x21, 0x43, 0x65], 0), 0x7E4A_8634);
+		assert_eq!(hash_32(&[0x21, 0x43], 0), 0xA0F7_B07A);
+		assert_eq!(hash_32(&[0x21], 0), 0x7266_1CF4);
+		assert_eq!(hash_32(b"aaaa", 0x9747_B28C), 0x5A97_808A);
+		assert_eq!(hash_32(b"Hello, world!", 1234), 0xFAF6_CDB3);
+		assert_eq!(
+			hash_32(b"The quick brown fox jumps over the lazy dog", 0),
+			0x2E4F_F723
+		);
+	}
+
+	#[test]
+	fn test_vectors_128() {
+		assert_eq!(hash_128(b"", 0), 0);
+		assert_eq!(
+			hash_128(b"The quick brown fox jumps over the lazy dog", 0),
+			0x7A43_3CA9_C49A_9347_E34B_BC7B_BC07_1B6C
+		);
+	}
+
+	#[test]
+	fn test_streaming() {
+		let bytes = b"The quick brown fox jumps over the lazy dog";
+		for split in 0..=bytes.len() {
+			let (head, tail) = bytes.split_at(split);
+
+			let mut hasher = Murmur3::with_seed(42);
+			hasher.write(head);
+			hasher.write(tail);
+			assert_eq!(hasher.finish(), u64::from(hash_32(bytes, 42)));
+
+			let mut hasher = Murmur3_128::with_seed(42);
+			hasher.write(head);
+			hasher.write(tail);
+			assert_eq!(hasher.finish_128(), hash_128(bytes, 42));
+		}
+
+		let mut hasher = Murmur3::default();
+		let mut hasher_128 = Murmur3_128::default();
+		for byte in bytes {
+			hasher.write(&[*byte]);
+			hasher_128.write(&[*byte]);
+		}
+		assert_eq!(hasher.finish(), u64::from(hash_32(bytes, 0)));
+		assert_eq!(hasher_128.finish_128(), hash_128(bytes, 0));
+	}
 
-		self.seed ^= bytes.len() as u32;
+	#[test]
+	fn test_build_hasher() {
+		let mut hasher = BuildMurmur3::new(1234).build_hasher();
+		hasher.write(b"Hello, world!");
+		assert_eq!(hasher.finish(), 0xFAF6_CDB3);
 
-		self.seed ^= self.seed >> 16;
-		self.seed = u32::wrapping_mul(self.seed, 0x85EB_CA6B);
-		self.seed ^= self.seed >> 13;
-		self.seed = u32::wrapping_mul(self.seed, 0xC2B2_AE35);
-		self.seed ^= self.seed >> 16;
+		let mut hasher = BuildMurmur3_128::new(0).build_hasher();
+		hasher.write(b"The quick brown fox jumps over the lazy dog");
+		assert_eq!(hasher.finish(), 0xE34B_BC7B_BC07_1B6C);
 	}
 }
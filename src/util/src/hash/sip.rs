@@ -0,0 +1,238 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{
+	collections::hash_map::RandomState,
+	hash::{BuildHasher, Hasher},
+};
+
+/// An implementation of the keyed [SipHash-2-4].
+///
+/// `SipHash` is a pseudorandom function, so as long as the key is secret, an attacker cannot craft
+/// inputs with colliding hashes. Use it for maps, which are fed by network or user data. A hasher
+/// with random keys is created by [`BuildSipHash24::random`].
+///
+/// Unlike the hasher used by `std::collections::HashMap`, the algorithm is fixed, so the hashes
+/// are stable across Rust versions.
+///
+/// [SipHash-2-4]: https://en.wikipedia.org/wiki/SipHash
+/// [`BuildSipHash24::random`]: struct.BuildSipHash24.html#method.random
+///
+/// # Example
+///
+/// ```
+/// use std::hash::Hasher;
+///
+/// use astral::util::hash::SipHash24;
+///
+/// let mut hasher = SipHash24::with_keys(0x0706_0504_0302_0100, 0x0F0E_0D0C_0B0A_0908);
+/// hasher.write(&[]);
+/// assert_eq!(hasher.finish(), 0x726F_DB47_DD0E_0E31);
+/// ```
+#[derive(Debug, Clone)]
+#[allow(missing_copy_implementations)]
+pub struct SipHash24 {
+	v0: u64,
+	v1: u64,
+	v2: u64,
+	v3: u64,
+	tail: u64,
+	tail_len: usize,
+	len: u64,
+}
+
+impl SipHash24 {
+	/// Creates a hasher with the given 128-bit key, split into two little-endian halves.
+	#[must_use]
+	pub fn with_keys(k0: u64, k1: u64) -> Self {
+		Self {
+			v0: k0 ^ 0x736F_6D65_7073_6575,
+			v1: k1 ^ 0x646F_7261_6E64_6F6D,
+			v2: k0 ^ 0x6C79_6765_6E65_7261,
+			v3: k1 ^ 0x7465_6462_7974_6573,
+			tail: 0,
+			tail_len: 0,
+			len: 0,
+		}
+	}
+
+	fn round(v: &mut [u64; 4]) {
+		v[0] = v[0].wrapping_add(v[1]);
+		v[1] = v[1].rotate_left(13) ^ v[0];
+		v[0] = v[0].rotate_left(32);
+		v[2] = v[2].wrapping_add(v[3]);
+		v[3] = v[3].rotate_left(16) ^ v[2];
+		v[0] = v[0].wrapping_add(v[3]);
+		v[3] = v[3].rotate_left(21) ^ v[0];
+		v[2] = v[2].wrapping_add(v[1]);
+		v[1] = v[1].rotate_left(17) ^ v[2];
+		v[2] = v[2].rotate_left(32);
+	}
+
+	fn compress(&mut self, m: u64) {
+		let mut v = [self.v0, self.v1, self.v2, self.v3];
+		v[3] ^= m;
+		Self::round(&mut v);
+		Self::round(&mut v);
+		v[0] ^= m;
+		let [v0, v1, v2, v3] = v;
+		self.v0 = v0;
+		self.v1 = v1;
+		self.v2 = v2;
+		self.v3 = v3;
+	}
+}
+
+impl Hasher for SipHash24 {
+	fn finish(&self) -> u64 {
+		let mut v = [self.v0, self.v1, self.v2, self.v3];
+		let b = (self.len & 0xFF) << 56 | self.tail;
+
+		v[3] ^= b;
+		Self::round(&mut v);
+		Self::round(&mut v);
+		v[0] ^= b;
+
+		v[2] ^= 0xFF;
+		for _ in 0..4 {
+			Self::round(&mut v);
+		}
+		v[0] ^ v[1] ^ v[2] ^ v[3]
+	}
+
+	fn write(&mut self, bytes: &[u8]) {
+		self.len = self.len.wrapping_add(bytes.len() as u64);
+		for byte in bytes {
+			self.tail |= u64::from(*byte) << (8 * self.tail_len);
+			self.tail_len += 1;
+			if self.tail_len == 8 {
+				self.compress(self.tail);
+				self.tail = 0;
+				self.tail_len = 0;
+			}
+		}
+	}
+}
+
+/// Creates [`SipHash24`] hashers with a fixed key.
+///
+/// Hashes depend on the key, so a `Subsystem` using random keys cannot load string tables written
+/// with other keys.
+///
+/// [`SipHash24`]: struct.SipHash24.html
+///
+/// # Example
+///
+/// ```
+/// use std::{collections::HashMap, hash::BuildHasher};
+///
+/// use astral::util::hash::BuildSipHash24;
+///
+/// let build_hasher = BuildSipHash24::random();
+/// assert_eq!(build_hasher.hash_one("cube"), build_hasher.hash_one("cube"));
+///
+/// let mut map = HashMap::with_hasher(build_hasher);
+/// map.insert("cube", 1);
+/// assert_eq!(map["cube"], 1);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BuildSipHash24 {
+	k0: u64,
+	k1: u64,
+}
+
+impl BuildSipHash24 {
+	/// Creates a `BuildHasher` for hashers with the given key.
+	#[must_use]
+	pub fn with_keys(k0: u64, k1: u64) -> Self {
+		Self { k0, k1 }
+	}
+
+	/// Creates a `BuildHasher` for hashers with a random key.
+	///
+	/// The key is derived from the random keys of the standard library's `RandomState`.
+	#[must_use]
+	pub fn random() -> Self {
+		Self {
+			k0: RandomState::new().build_hasher().finish(),
+			k1: RandomState::new().build_hasher().finish(),
+		}
+	}
+
+	/// Returns the key of the created hashers.
+	#[must_use]
+	pub fn keys(self) -> (u64, u64) {
+		(self.k0, self.k1)
+	}
+}
+
+impl BuildHasher for BuildSipHash24 {
+	type Hasher = SipHash24;
+
+	fn build_hasher(&self) -> SipHash24 {
+		SipHash24::with_keys(self.k0, self.k1)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BuildSipHash24, SipHash24};
+	use std::hash::{BuildHasher, Hasher};
+
+	const K0: u64 = 0x0706_0504_0302_0100;
+	const K1: u64 = 0x0F0E_0D0C_0B0A_0908;
+
+	#[allow(clippy::cast_possible_truncation)]
+	fn hash(len: usize) -> u64 {
+		let bytes = (0..len).map(|byte| byte as u8).collect::<Vec<_>>();
+		let mut hasher = SipHash24::with_keys(K0, K1);
+		hasher.write(&bytes);
+		hasher.finish()
+	}
+
+	/// Test vectors of the reference implementation with the key `00 01 .. 0F` and the messages
+	/// `[]`, `[00]`, `[00, 01]`, ...
+	#[test]
+	fn test_vectors() {
+		assert_eq!(hash(0), 0x726F_DB47_DD0E_0E31);
+		assert_eq!(hash(1), 0x74F8_39C5_93DC_67FD);
+		assert_eq!(hash(7), 0xAB02_00F5_8B01_D137);
+		assert_eq!(hash(8), 0x93F5_F579_9A93_2462);
+		assert_eq!(hash(15), 0xA129_CA61_49BE_45E5);
+		assert_eq!(hash(63), 0x958A_324C_EB06_4572);
+	}
+
+	#[test]
+	fn test_streaming() {
+		let bytes = (0..63).collect::<Vec<u8>>();
+		for split in 0..=bytes.len() {
+			let (head, tail) = bytes.split_at(split);
+			let mut hasher = SipHash24::with_keys(K0, K1);
+			hasher.write(head);
+			hasher.write(tail);
+			assert_eq!(hasher.finish(), hash(63));
+		}
+	}
+
+	#[test]
+	fn test_build_hasher() {
+		let mut hasher = BuildSipHash24::with_keys(K0, K1).build_hasher();
+		hasher.write(&[0]);
+		assert_eq!(hasher.finish(), hash(1));
+
+		let build_hasher = BuildSipHash24::random();
+		assert_ne!(build_hasher.keys(), BuildSipHash24::random().keys());
+	}
+}
@@ -0,0 +1,668 @@
+// Copyright (c) Astral Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+// Written by Tim Diekmann <tim.diekmann@3dvision.de>, October 2026
+
+use std::{
+	fmt::{self, Debug, Formatter},
+	hash::{BuildHasher, Hasher},
+};
+
+const PRIME32_1: u64 = 0x9E37_79B1;
+const PRIME32_2: u64 = 0x85EB_CA77;
+const PRIME32_3: u64 = 0xC2B2_AE3D;
+const PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+const PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+const PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+const PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+	let mut buffer = [0; 4];
+	buffer.copy_from_slice(&bytes[offset..offset + 4]);
+	u32::from_le_bytes(buffer)
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+	let mut buffer = [0; 8];
+	buffer.copy_from_slice(&bytes[offset..offset + 8]);
+	u64::from_le_bytes(buffer)
+}
+
+fn avalanche_xxh64(mut h: u64) -> u64 {
+	h ^= h >> 33;
+	h = h.wrapping_mul(PRIME64_2);
+	h ^= h >> 29;
+	h = h.wrapping_mul(PRIME64_3);
+	h ^ (h >> 32)
+}
+
+/// An implementation of the 64-bit [xxHash] (`XXH64`).
+///
+/// xxHash processes 32 bytes per round and is well suited for large buffers like asset content.
+/// The hasher buffers incomplete blocks across calls to [`write`], so the result does not depend
+/// on how the input is split up. It is not resistant against collision attacks.
+///
+/// [xxHash]: https://github.com/Cyan4973/xxHash
+/// [`write`]: #method.write
+///
+/// # Example
+///
+/// ```
+/// use std::hash::Hasher;
+///
+/// use astral::util::hash::XxHash64;
+///
+/// let mut hasher = XxHash64::default();
+/// hasher.write(b"abc");
+/// assert_eq!(hasher.finish(), 0x44BC_2CF5_AD77_0999);
+/// ```
+#[derive(Debug, Clone)]
+#[allow(missing_copy_implementations)]
+pub struct XxHash64 {
+	seed: u64,
+	acc: [u64; 4],
+	buffer: [u8; 32],
+	buffered: usize,
+	len: u64,
+}
+
+impl XxHash64 {
+	/// Creates a hasher with the given seed.
+	#[must_use]
+	pub fn with_seed(seed: u64) -> Self {
+		Self {
+			seed,
+			acc: [
+				seed.wrapping_add(PRIME64_1).wrapping_add(PRIME64_2),
+				seed.wrapping_add(PRIME64_2),
+				seed,
+				seed.wrapping_sub(PRIME64_1),
+			],
+			buffer: [0; 32],
+			buffered: 0,
+			len: 0,
+		}
+	}
+
+	fn round(acc: u64, input: u64) -> u64 {
+		acc.wrapping_add(input.wrapping_mul(PRIME64_2))
+			.rotate_left(31)
+			.wrapping_mul(PRIME64_1)
+	}
+
+	fn merge_round(acc: u64, value: u64) -> u64 {
+		(acc ^ Self::round(0, value))
+			.wrapping_mul(PRIME64_1)
+			.wrapping_add(PRIME64_4)
+	}
+
+	fn write_block(&mut self, block: &[u8]) {
+		for (lane, acc) in self.acc.iter_mut().enumerate() {
+			*acc = Self::round(*acc, read_u64(block, lane * 8));
+		}
+	}
+}
+
+impl Default for XxHash64 {
+	fn default() -> Self {
+		Self::with_seed(0)
+	}
+}
+
+impl Hasher for XxHash64 {
+	fn finish(&self) -> u64 {
+		let mut h = if self.len >= 32 {
+			let [v1, v2, v3, v4] = self.acc;
+			let mut h = v1
+				.rotate_left(1)
+				.wrapping_add(v2.rotate_left(7))
+				.wrapping_add(v3.rotate_left(12))
+				.wrapping_add(v4.rotate_left(18));
+			for acc in &self.acc {
+				h = Self::merge_round(h, *acc);
+			}
+			h
+		} else {
+			self.seed.wrapping_add(PRIME64_5)
+		};
+		h = h.wrapping_add(self.len);
+
+		let mut remainder = &self.buffer[..self.buffered];
+		while remainder.len() >= 8 {
+			h ^= Self::round(0, read_u64(remainder, 0));
+			h = h
+				.rotate_left(27)
+				.wrapping_mul(PRIME64_1)
+				.wrapping_add(PRIME64_4);
+			remainder = &remainder[8..];
+		}
+		if remainder.len() >= 4 {
+			h ^= u64::from(read_u32(remainder, 0)).wrapping_mul(PRIME64_1);
+			h = h
+				.rotate_left(23)
+				.wrapping_mul(PRIME64_2)
+				.wrapping_add(PRIME64_3);
+			remainder = &remainder[4..];
+		}
+		for byte in remainder {
+			h ^= u64::from(*byte).wrapping_mul(PRIME64_5);
+			h = h.rotate_left(11).wrapping_mul(PRIME64_1);
+		}
+		avalanche_xxh64(h)
+	}
+
+	fn write(&mut self, mut bytes: &[u8]) {
+		self.len = self.len.wrapping_add(bytes.len() as u64);
+
+		if self.buffered > 0 {
+			let n = bytes.len().min(32 - self.buffered);
+			self.buffer[self.buffered..self.buffered + n].copy_from_slice(&bytes[..n]);
+			self.buffered += n;
+			bytes = &bytes[n..];
+			if self.buffered < 32 {
+				return;
+			}
+			let buffer = self.buffer;
+			self.write_block(&buffer);
+			self.buffered = 0;
+		}
+
+		let mut blocks = bytes.chunks_exact(32);
+		for block in &mut blocks {
+			self.write_block(block);
+		}
+		let remainder = blocks.remainder();
+		self.buffer[..remainder.len()].copy_from_slice(remainder);
+		self.buffered = remainder.len();
+	}
+}
+
+/// Creates [`XxHash64`] hashers with a fixed seed.
+///
+/// [`XxHash64`]: struct.XxHash64.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BuildXxHash64 {
+	seed: u64,
+}
+
+impl BuildXxHash64 {
+	/// Creates a `BuildHasher` for hashers with the given seed.
+	#[must_use]
+	pub fn new(seed: u64) -> Self {
+		Self { seed }
+	}
+
+	/// Returns the seed of the created hashers.
+	#[must_use]
+	pub fn seed(self) -> u64 {
+		self.seed
+	}
+}
+
+impl BuildHasher for BuildXxHash64 {
+	type Hasher = XxHash64;
+
+	fn build_hasher(&self) -> XxHash64 {
+		XxHash64::with_seed(self.seed)
+	}
+}
+
+/// The default secret of XXH3.
+const SECRET: [u8; SECRET_SIZE] = [
+	0xB8, 0xFE, 0x6C, 0x39, 0x23, 0xA4, 0x4B, 0xBE, 0x7C, 0x01, 0x81, 0x2C, 0xF7, 0x21, 0xAD, 0x1C,
+	0xDE, 0xD4, 0x6D, 0xE9, 0x83, 0x90, 0x97, 0xDB, 0x72, 0x40, 0xA4, 0xA4, 0xB7, 0xB3, 0x67, 0x1F,
+	0xCB, 0x79, 0xE6, 0x4E, 0xCC, 0xC0, 0xE5, 0x78, 0x82, 0x5A, 0xD0, 0x7D, 0xCC, 0xFF, 0x72, 0x21,
+	0xB8, 0x08, 0x46, 0x74, 0xF7, 0x43, 0x24, 0x8E, 0xE0, 0x35, 0x90, 0xE6, 0x81, 0x3A, 0x26, 0x4C,
+	0x3C, 0x28, 0x52, 0xBB, 0x91, 0xC3, 0x00, 0xCB, 0x88, 0xD0, 0x65, 0x8B, 0x1B, 0x53, 0x2E, 0xA3,
+	0x71, 0x64, 0x48, 0x97, 0xA2, 0x0D, 0xF9, 0x4E, 0x38, 0x19, 0xEF, 0x46, 0xA9, 0xDE, 0xAC, 0xD8,
+	0xA8, 0xFA, 0x76, 0x3F, 0xE3, 0x9C, 0x34, 0x3F, 0xF9, 0xDC, 0xBB, 0xC7, 0xC7, 0x0B, 0x4F, 0x1D,
+	0x8A, 0x51, 0xE0, 0x4B, 0xCD, 0xB4, 0x59, 0x31, 0xC8, 0x9F, 0x7E, 0xC9, 0xD9, 0x78, 0x73, 0x64,
+	0xEA, 0xC5, 0xAC, 0x83, 0x34, 0xD3, 0xEB, 0xC3, 0xC5, 0x81, 0xA0, 0xFF, 0xFA, 0x13, 0x63, 0xEB,
+	0x17, 0x0D, 0xDD, 0x51, 0xB7, 0xF0, 0xDA, 0x49, 0xD3, 0x16, 0x55, 0x26, 0x29, 0xD4, 0x68, 0x9E,
+	0x2B, 0x16, 0xBE, 0x58, 0x7D, 0x47, 0xA1, 0xFC, 0x8F, 0xF8, 0xB8, 0xD1, 0x7A, 0xD0, 0x31, 0xCE,
+	0x45, 0xCB, 0x3A, 0x8F, 0x95, 0x16, 0x04, 0x28, 0xAF, 0xD7, 0xFB, 0xCA, 0xBB, 0x4B, 0x40, 0x7E,
+];
+const SECRET_SIZE: usize = 192;
+/// The minimum size of a secret supported by the reference implementation.
+const SECRET_SIZE_MIN: usize = 136;
+const STRIPE_LEN: usize = 64;
+const STRIPES_PER_BLOCK: usize = (SECRET_SIZE - STRIPE_LEN) / 8;
+const BUFFER_SIZE: usize = 256;
+/// Inputs up to this length are hashed without the accumulators.
+const MID_SIZE_MAX: usize = 240;
+
+fn fold_mul(lhs: u64, rhs: u64) -> u64 {
+	let product = u128::from(lhs) * u128::from(rhs);
+	#[allow(clippy::cast_possible_truncation)]
+	let folded = product as u64 ^ (product >> 64) as u64;
+	folded
+}
+
+fn avalanche_xxh3(mut h: u64) -> u64 {
+	h ^= h >> 37;
+	h = h.wrapping_mul(0x1656_6791_9E37_79F9);
+	h ^ (h >> 32)
+}
+
+fn rrmxmx(mut h: u64, len: u64) -> u64 {
+	h ^= h.rotate_left(49) ^ h.rotate_left(24);
+	h = h.wrapping_mul(0x9FB2_1C65_1E98_DF25);
+	h ^= (h >> 35).wrapping_add(len);
+	h = h.wrapping_mul(0x9FB2_1C65_1E98_DF25);
+	h ^ (h >> 28)
+}
+
+fn mix_16(bytes: &[u8], offset: usize, secret_offset: usize, seed: u64) -> u64 {
+	fold_mul(
+		read_u64(bytes, offset) ^ read_u64(&SECRET, secret_offset).wrapping_add(seed),
+		read_u64(bytes, offset + 8) ^ read_u64(&SECRET, secret_offset + 8).wrapping_sub(seed),
+	)
+}
+
+/// Hashes inputs of at most `MID_SIZE_MAX` bytes.
+#[allow(clippy::cast_possible_truncation)]
+fn hash_short(bytes: &[u8], seed: u64) -> u64 {
+	let len = bytes.len();
+	let len_u64 = len as u64;
+	match len {
+		0 => avalanche_xxh64(seed ^ read_u64(&SECRET, 56) ^ read_u64(&SECRET, 64)),
+		1..=3 => {
+			let combined = u32::from(bytes[0]) << 16
+				| u32::from(bytes[len >> 1]) << 24
+				| u32::from(bytes[len - 1])
+				| (len as u32) << 8;
+			let bitflip = u64::from(read_u32(&SECRET, 0) ^ read_u32(&SECRET, 4)).wrapping_add(seed);
+			avalanche_xxh64(u64::from(combined) ^ bitflip)
+		}
+		4..=8 => {
+			let seed = seed ^ u64::from((seed as u32).swap_bytes()) << 32;
+			let input = u64::from(read_u32(bytes, len - 4))
+				.wrapping_add(u64::from(read_u32(bytes, 0)) << 32);
+			let bitflip = (read_u64(&SECRET, 8) ^ read_u64(&SECRET, 16)).wrapping_sub(seed);
+			rrmxmx(input ^ bitflip, len_u64)
+		}
+		9..=16 => {
+			let bitflip_low = (read_u64(&SECRET, 24) ^ read_u64(&SECRET, 32)).wrapping_add(seed);
+			let bitflip_high = (read_u64(&SECRET, 40) ^ read_u64(&SECRET, 48)).wrapping_sub(seed);
+			let low = read_u64(bytes, 0) ^ bitflip_low;
+			let high = read_u64(bytes, len - 8) ^ bitflip_high;
+			avalanche_xxh3(
+				len_u64
+					.wrapping_add(low.swap_bytes())
+					.wrapping_add(high)
+					.wrapping_add(fold_mul(low, high)),
+			)
+		}
+		17..=128 => {
+			let mut acc = len_u64.wrapping_mul(PRIME64_1);
+			let rounds = (len - 1) / 32;
+			for round in (0..=rounds).rev() {
+				acc = acc
+					.wrapping_add(mix_16(bytes, 16 * round, 32 * round, seed))
+					.wrapping_add(mix_16(bytes, len - 16 * (round + 1), 32 * round + 16, seed));
+			}
+			avalanche_xxh3(acc)
+		}
+		_ => {
+			let mut acc = len_u64.wrapping_mul(PRIME64_1);
+			for round in 0..8 {
+				acc = acc.wrapping_add(mix_16(bytes, 16 * round, 16 * round, seed));
+			}
+			acc = avalanche_xxh3(acc);
+			for round in 8..len / 16 {
+				acc = acc.wrapping_add(mix_16(bytes, 16 * round, 16 * (round - 8) + 3, seed));
+			}
+			acc = acc.wrapping_add(mix_16(bytes, len - 16, SECRET_SIZE_MIN - 17, seed));
+			avalanche_xxh3(acc)
+		}
+	}
+}
+
+/// An implementation of the 64-bit [XXH3] hash.
+///
+/// XXH3 is the successor of [`XxHash64`]. It is faster for both small inputs and large buffers
+/// like asset content. The hasher buffers the input across calls to [`write`], so the result does
+/// not depend on how the input is split up. It is not resistant against collision attacks.
+///
+/// [XXH3]: https://github.com/Cyan4973/xxHash
+/// [`XxHash64`]: struct.XxHash64.html
+/// [`write`]: #method.write
+///
+/// # Example
+///
+/// ```
+/// use std::hash::Hasher;
+///
+/// use astral::util::hash::Xxh3;
+///
+/// let hasher = Xxh3::default();
+/// assert_eq!(hasher.finish(), 0x2D06_8005_38D3_94C2);
+/// ```
+#[derive(Clone)]
+pub struct Xxh3 {
+	seed: u64,
+	secret: [u8; SECRET_SIZE],
+	acc: [u64; 8],
+	buffer: [u8; BUFFER_SIZE],
+	buffered: usize,
+	stripes: usize,
+	len: u64,
+}
+
+impl Xxh3 {
+	/// Creates a hasher with the given seed.
+	#[must_use]
+	pub fn with_seed(seed: u64) -> Self {
+		let mut secret = SECRET;
+		if seed != 0 {
+			for (index, chunk) in secret.chunks_exact_mut(16).enumerate() {
+				let low = read_u64(&SECRET, 16 * index).wrapping_add(seed);
+				let high = read_u64(&SECRET, 16 * index + 8).wrapping_sub(seed);
+				chunk[..8].copy_from_slice(&low.to_le_bytes());
+				chunk[8..].copy_from_slice(&high.to_le_bytes());
+			}
+		}
+		Self {
+			seed,
+			secret,
+			acc: [
+				PRIME32_3, PRIME64_1, PRIME64_2, PRIME64_3, PRIME64_4, PRIME32_2, PRIME64_5,
+				PRIME32_1,
+			],
+			buffer: [0; BUFFER_SIZE],
+			buffered: 0,
+			stripes: 0,
+			len: 0,
+		}
+	}
+
+	fn accumulate_stripe(acc: &mut [u64; 8], stripe: &[u8], secret: &[u8]) {
+		for lane in 0..8 {
+			let value = read_u64(stripe, 8 * lane);
+			let key = value ^ read_u64(secret, 8 * lane);
+			acc[lane ^ 1] = acc[lane ^ 1].wrapping_add(value);
+			acc[lane] = acc[lane].wrapping_add((key & 0xFFFF_FFFF).wrapping_mul(key >> 32));
+		}
+	}
+
+	fn scramble(acc: &mut [u64; 8], secret: &[u8]) {
+		for (lane, value) in acc.iter_mut().enumerate() {
+			let key = read_u64(secret, SECRET_SIZE - STRIPE_LEN + 8 * lane);
+			*value = (*value ^ (*value >> 47) ^ key).wrapping_mul(PRIME32_1);
+		}
+	}
+
+	/// Accumulates the stripes of `input` and scrambles the accumulators after every block.
+	fn consume_stripes(
+		acc: &mut [u64; 8],
+		stripes: &mut usize,
+		input: &[u8],
+		secret: &[u8; SECRET_SIZE],
+	) {
+		for stripe in input.chunks_exact(STRIPE_LEN) {
+			Self::accumulate_stripe(acc, stripe, &secret[*stripes * 8..]);
+			*stripes += 1;
+			if *stripes == STRIPES_PER_BLOCK {
+				Self::scramble(acc, secret);
+				*stripes = 0;
+			}
+		}
+	}
+
+	fn merge_accumulators(&self, acc: &[u64; 8]) -> u64 {
+		let mut result = self.len.wrapping_mul(PRIME64_1);
+		for pair in 0..4 {
+			result = result.wrapping_add(fold_mul(
+				acc[2 * pair] ^ read_u64(&self.secret, 11 + 16 * pair),
+				acc[2 * pair + 1] ^ read_u64(&self.secret, 11 + 16 * pair + 8),
+			));
+		}
+		avalanche_xxh3(result)
+	}
+}
+
+impl Default for Xxh3 {
+	fn default() -> Self {
+		Self::with_seed(0)
+	}
+}
+
+impl Debug for Xxh3 {
+	fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+		fmt.debug_struct("Xxh3")
+			.field("seed", &self.seed)
+			.field("len", &self.len)
+			.finish_non_exhaustive()
+	}
+}
+
+impl Hasher for Xxh3 {
+	#[allow(clippy::cast_possible_truncation)]
+	fn finish(&self) -> u64 {
+		if self.len <= MID_SIZE_MAX as u64 {
+			return hash_short(&self.buffer[..self.len as usize], self.seed);
+		}
+
+		let mut acc = self.acc;
+		let mut stripes = self.stripes;
+		let mut last_stripe = [0; STRIPE_LEN];
+		if self.buffered >= STRIPE_LEN {
+			let consumed = (self.buffered - 1) / STRIPE_LEN * STRIPE_LEN;
+			Self::consume_stripes(
+				&mut acc,
+				&mut stripes,
+				&self.buffer[..consumed],
+				&self.secret,
+			);
+			last_stripe.copy_from_slice(&self.buffer[self.buffered - STRIPE_LEN..self.buffered]);
+		} else {
+			// The end of the buffer still contains the end of the previously consumed input
+			let previous = STRIPE_LEN - self.buffered;
+			last_stripe[..previous].copy_from_slice(&self.buffer[BUFFER_SIZE - previous..]);
+			last_stripe[previous..].copy_from_slice(&self.buffer[..self.buffered]);
+		}
+		Self::accumulate_stripe(
+			&mut acc,
+			&last_stripe,
+			&self.secret[SECRET_SIZE - STRIPE_LEN - 7..],
+		);
+		self.merge_accumulators(&acc)
+	}
+
+	fn write(&mut self, mut bytes: &[u8]) {
+		self.len = self.len.wrapping_add(bytes.len() as u64);
+
+		if self.buffered + bytes.len() <= BUFFER_SIZE {
+			self.buffer[self.buffered..self.buffered + bytes.len()].copy_from_slice(bytes);
+			self.buffered += bytes.len();
+			return;
+		}
+
+		if self.buffered > 0 {
+			let n = BUFFER_SIZE - self.buffered;
+			self.buffer[self.buffered..].copy_from_slice(&bytes[..n]);
+			bytes = &bytes[n..];
+			Self::consume_stripes(&mut self.acc, &mut self.stripes, &self.buffer, &self.secret);
+			self.buffered = 0;
+		}
+
+		// Keep at least one byte in the buffer, the last stripe is processed in `finish`
+		if bytes.len() > BUFFER_SIZE {
+			let consumed = (bytes.len() - 1) / STRIPE_LEN * STRIPE_LEN;
+			let (stripes, remainder) = bytes.split_at(consumed);
+			Self::consume_stripes(&mut self.acc, &mut self.stripes, stripes, &self.secret);
+			self.buffer[BUFFER_SIZE - STRIPE_LEN..]
+				.copy_from_slice(&stripes[consumed - STRIPE_LEN..]);
+			bytes = remainder;
+		}
+		debug_assert!(bytes.len() <= BUFFER_SIZE);
+
+		self.buffer[..bytes.len()].copy_from_slice(bytes);
+		self.buffered = bytes.len();
+	}
+}
+
+/// Creates [`Xxh3`] hashers with a fixed seed.
+///
+/// [`Xxh3`]: struct.Xxh3.html
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BuildXxh3 {
+	seed: u64,
+}
+
+impl BuildXxh3 {
+	/// Creates a `BuildHasher` for hashers with the given seed.
+	#[must_use]
+	pub fn new(seed: u64) -> Self {
+		Self { seed }
+	}
+
+	/// Returns the seed of the created hashers.
+	#[must_use]
+	pub fn seed(self) -> u64 {
+		self.seed
+	}
+}
+
+impl BuildHasher for BuildXxh3 {
+	type Hasher = Xxh3;
+
+	fn build_hasher(&self) -> Xxh3 {
+		Xxh3::with_seed(self.seed)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BuildXxHash64, BuildXxh3, XxHash64, Xxh3, PRIME32_1};
+	use std::hash::{BuildHasher, Hasher};
+
+	/// The prime used by `xxhsum` to generate the sanity buffer and as seed for XXH3.
+	const SANITY_PRIME64: u64 = 0x9E37_79B1_85EB_CA8D;
+
+	/// The sanity buffer of `xxhsum`, which is used to generate the reference test vectors.
+	#[allow(clippy::cast_possible_truncation)]
+	fn sanity_buffer() -> Vec<u8> {
+		let mut generator = PRIME32_1;
+		(0..2367)
+			.map(|_| {
+				let byte = (generator >> 56) as u8;
+				generator = generator.wrapping_mul(SANITY_PRIME64);
+				byte
+			})
+			.collect()
+	}
+
+	fn xxh64(bytes: &[u8], seed: u64) -> u64 {
+		let mut hasher = XxHash64::with_seed(seed);
+		hasher.write(bytes);
+		hasher.finish()
+	}
+
+	fn xxh3(bytes: &[u8], seed: u64) -> u64 {
+		let mut hasher = Xxh3::with_seed(seed);
+		hasher.write(bytes);
+		hasher.finish()
+	}
+
+	#[test]
+	fn test_vectors_xxh64() {
+		let buffer = sanity_buffer();
+		let vectors = [
+			(0, 0, 0xEF46_DB37_51D8_E999),
+			(0, PRIME32_1, 0xAC75_FDA2_929B_17EF),
+			(1, 0, 0xE934_A84A_DB05_2768),
+			(1, PRIME32_1, 0x5014_6076_43A9_B4C3),
+			(4, 0, 0x9136_A0DC_A574_57EE),
+			(14, 0, 0x8282_DCC4_994E_35C8),
+			(14, PRIME32_1, 0xC3BD_6BF6_3DEB_6DF0),
+			(222, 0, 0xB641_AE8C_B691_C174),
+			(222, PRIME32_1, 0x20CB_8AB7_AE10_C14A),
+		];
+		for &(len, seed, hash) in &vectors {
+			assert_eq!(
+				xxh64(&buffer[..len], seed),
+				hash,
+				"len: {len}, seed: {seed}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_vectors_xxh3() {
+		let buffer = sanity_buffer();
+		let vectors = [
+			(0, 0x2D06_8005_38D3_94C2, 0xA8A6_B918_B2F0_364A),
+			(1, 0xC44B_DFF4_074E_ECDB, 0x032B_E332_DD76_6EF8),
+			(6, 0x27B5_6A84_CD2D_7325, 0x8458_9C11_6AB5_9AB9),
+			(12, 0xA713_DAF0_DFBB_77E7, 0xE730_3E1B_2336_DE0E),
+			(24, 0xA3FE_70BF_9D35_10EB, 0x850E_80FC_35BD_D690),
+			(48, 0x397D_A259_ECBA_1F11, 0xADC2_CBAA_44AC_C616),
+			(80, 0xBCDE_FBBB_2C47_C90A, 0xC6DD_0CB6_9953_2E73),
+			(195, 0xCD94_217E_E362_EC3A, 0xBA68_003D_370C_B3D9),
+			(403, 0xCDEB_804D_65C6_DEA4, 0x6259_F6EC_FD64_43FD),
+			(512, 0x617E_4959_9013_CB6B, 0x3CE4_57DE_14C2_7708),
+			(2048, 0xDD59_E2C3_A5F0_38E0, 0x66F8_1670_669A_BABC),
+			(2240, 0x6E73_A905_39CF_2948, 0x757B_A848_7D1B_5247),
+			(2367, 0xCB37_AEB9_E5D3_61ED, 0xD2DB_3415_B942_B42A),
+		];
+		for &(len, hash, seeded_hash) in &vectors {
+			assert_eq!(xxh3(&buffer[..len], 0), hash, "len: {len}");
+			assert_eq!(
+				xxh3(&buffer[..len], SANITY_PRIME64),
+				seeded_hash,
+				"len: {len}"
+			);
+		}
+	}
+
+	#[test]
+	fn test_streaming() {
+		let buffer = sanity_buffer();
+		for &len in &[0, 31, 32, 33, 240, 241, 256, 257, 1024, 1025, 2367] {
+			let bytes = &buffer[..len];
+			let expected_xxh64 = xxh64(bytes, 42);
+			let expected_xxh3 = xxh3(bytes, 42);
+			for &chunk_size in &[1, 7, 32, 63, 64, 65, 255, 256, 257] {
+				let mut hasher = XxHash64::with_seed(42);
+				let mut hasher_xxh3 = Xxh3::with_seed(42);
+				for chunk in bytes.chunks(chunk_size) {
+					hasher.write(chunk);
+					hasher_xxh3.write(chunk);
+				}
+				assert_eq!(
+					hasher.finish(),
+					expected_xxh64,
+					"len: {len}, chunk: {chunk_size}"
+				);
+				assert_eq!(
+					hasher_xxh3.finish(),
+					expected_xxh3,
+					"len: {len}, chunk: {chunk_size}"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_build_hasher() {
+		let mut hasher = BuildXxHash64::new(PRIME32_1).build_hasher();
+		hasher.write(&[]);
+		assert_eq!(hasher.finish(), 0xAC75_FDA2_929B_17EF);
+
+		let mut hasher = BuildXxh3::new(SANITY_PRIME64).build_hasher();
+		hasher.write(&[]);
+		assert_eq!(hasher.finish(), 0xA8A6_B918_B2F0_364A);
+	}
+}